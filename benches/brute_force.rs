@@ -0,0 +1,52 @@
+//! Compares the backtracking solver used by `find_brute_force`
+//! to the original one that applies each guess with a `Changer`.
+//!
+//! Run with `cargo bench --bench brute_force`.
+
+use std::time::{Duration, Instant};
+
+use sudoku_rust::solve::{find_brute_force, find_brute_force_with_changer, BruteForceResult};
+use sudoku_rust::{Board, Options, Parse, Parser};
+
+const PUZZLES: [&str; 8] = [
+    ".......3....13.4....1.....54.26......8..75..9...4..6.2.......8..7..9...6834......",
+    ".2....3..395.....27.6..........6197.5..4....3........1481..2.....2.4........7...8",
+    "3....76...5...3..48...2..3..8.5...49...63.8...............7...57......6..19....87",
+    "....3...81....627..64..715.....7286........27.8........4......57....5.9.5.96.....",
+    "...4...6.....1...8.16.8.5.9...........53.7......24.7...5......2..18..6938...9.1..",
+    "1.......2.9.4...5...6...7...5.9.3.......7.......85..4.7.....6...3...9.8...2.....1",
+    "8..........36......7..9.2...5...7.......457.....1...3...1....68..85...1..9....4..",
+    "1....7.9..3..2...8..96..5....53..9...1..8...26....4...3......1..4......7..7...3..",
+];
+
+const ROUNDS: u32 = 10;
+
+fn main() {
+    let parser = Parse::packed_with_options(Options::errors());
+    let boards = PUZZLES
+        .iter()
+        .map(|puzzle| parser.parse_simple(puzzle))
+        .collect::<Vec<_>>();
+
+    let backtracking = time(&boards, find_brute_force);
+    let changer = time(&boards, find_brute_force_with_changer);
+
+    println!("puzzles       {:>12}", boards.len());
+    println!("backtracking  {:>12?}", backtracking / ROUNDS);
+    println!("changer       {:>12?}", changer / ROUNDS);
+    println!(
+        "speedup       {:>11.1}x",
+        changer.as_secs_f64() / backtracking.as_secs_f64()
+    );
+}
+
+/// Returns the total time taken to check every board for a unique solution.
+fn time(boards: &[Board], solve: fn(&Board, bool, u32, usize) -> BruteForceResult) -> Duration {
+    let start = Instant::now();
+    for _ in 0..ROUNDS {
+        for board in boards {
+            assert!(solve(board, false, 0, 2).is_solved());
+        }
+    }
+    start.elapsed()
+}
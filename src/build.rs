@@ -1,5 +1,11 @@
+mod clue_symmetry;
 mod finder;
 mod generator;
+mod minimizer;
+mod target;
 
-pub use finder::Finder;
+pub use clue_symmetry::ClueSymmetry;
+pub use finder::{Finder, Validations};
 pub use generator::Generator;
+pub use minimizer::{find_redundant_clues, has_unique_solution, Minimizer};
+pub use target::Target;
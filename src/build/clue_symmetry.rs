@@ -0,0 +1,133 @@
+use std::fmt;
+use std::str::FromStr;
+
+use crate::layout::{Cell, CellSet};
+use crate::puzzle::Symmetry;
+
+/// The symmetry a [`Finder`][`crate::build::Finder`] keeps in the layout of the clues
+/// by removing each clue together with every cell it maps to.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum ClueSymmetry {
+    /// Removes one clue at a time.
+    #[default]
+    None,
+    /// Looks the same after a half turn.
+    Rotate180,
+    /// Looks the same after a quarter turn.
+    Rotate90,
+    /// Mirrors the left and right sides.
+    Horizontal,
+    /// Mirrors the top and bottom.
+    Vertical,
+    /// Mirrors across the diagonal from the top-left to the bottom-right.
+    Diagonal,
+}
+
+impl ClueSymmetry {
+    /// Every clue symmetry from least to most constrained.
+    pub const ALL: [ClueSymmetry; 6] = [
+        Self::None,
+        Self::Rotate180,
+        Self::Horizontal,
+        Self::Vertical,
+        Self::Diagonal,
+        Self::Rotate90,
+    ];
+
+    /// Returns the cells that must be removed along with the given cell.
+    pub fn orbit(&self, cell: Cell) -> CellSet {
+        let symmetry = self.symmetry();
+        let mut orbit = CellSet::empty();
+        let mut next = cell;
+        while !orbit.has(next) {
+            orbit += next;
+            next = symmetry.cell(next);
+        }
+        orbit
+    }
+
+    /// Returns true if every cell's orbit is entirely inside or outside the set.
+    pub fn is_symmetric(&self, cells: CellSet) -> bool {
+        cells
+            .iter()
+            .all(|cell| self.orbit(cell).is_subset_of(cells))
+    }
+
+    fn symmetry(&self) -> Symmetry {
+        match self {
+            Self::None => Symmetry::identity(),
+            Self::Rotate180 => Symmetry::rotate(2),
+            Self::Rotate90 => Symmetry::rotate(1),
+            Self::Horizontal => Symmetry::reflect_horizontally(),
+            Self::Vertical => Symmetry::reflect_vertically(),
+            Self::Diagonal => Symmetry::transpose(),
+        }
+    }
+}
+
+impl fmt::Display for ClueSymmetry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::None => "none",
+            Self::Rotate180 => "180",
+            Self::Rotate90 => "90",
+            Self::Horizontal => "horizontal",
+            Self::Vertical => "vertical",
+            Self::Diagonal => "diagonal",
+        })
+    }
+}
+
+impl FromStr for ClueSymmetry {
+    type Err = String;
+
+    fn from_str(label: &str) -> Result<Self, Self::Err> {
+        match label.trim().to_ascii_lowercase().as_str() {
+            "none" => Ok(Self::None),
+            "180" | "rotate180" | "rotational" => Ok(Self::Rotate180),
+            "90" | "rotate90" => Ok(Self::Rotate90),
+            "horizontal" => Ok(Self::Horizontal),
+            "vertical" => Ok(Self::Vertical),
+            "diagonal" => Ok(Self::Diagonal),
+            _ => Err(format!("Unknown symmetry \"{}\"", label)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::layout::cells::cell::cell;
+    use crate::layout::cells::cell_set::cells;
+
+    use super::*;
+
+    #[test]
+    fn orbits_hold_every_mapped_cell() {
+        assert_eq!(cells!("B3"), ClueSymmetry::None.orbit(cell!("B3")));
+        assert_eq!(cells!("B3 H7"), ClueSymmetry::Rotate180.orbit(cell!("B3")));
+        assert_eq!(
+            cells!("B3 C8 H7 G2"),
+            ClueSymmetry::Rotate90.orbit(cell!("B3"))
+        );
+        assert_eq!(cells!("B3 B7"), ClueSymmetry::Horizontal.orbit(cell!("B3")));
+        assert_eq!(cells!("B3 H3"), ClueSymmetry::Vertical.orbit(cell!("B3")));
+        assert_eq!(cells!("B3 C2"), ClueSymmetry::Diagonal.orbit(cell!("B3")));
+        assert_eq!(cells!("E5"), ClueSymmetry::Rotate90.orbit(cell!("E5")));
+    }
+
+    #[test]
+    fn detects_symmetric_layouts() {
+        assert!(ClueSymmetry::Rotate180.is_symmetric(cells!("A1 J9 E5")));
+        assert!(!ClueSymmetry::Rotate180.is_symmetric(cells!("A1 J1")));
+        assert!(ClueSymmetry::None.is_symmetric(cells!("A1 J1")));
+    }
+
+    #[test]
+    fn parses_labels() {
+        for symmetry in ClueSymmetry::ALL {
+            assert_eq!(Ok(symmetry), symmetry.to_string().parse());
+        }
+        assert_eq!(Ok(ClueSymmetry::Rotate180), "Rotational".parse());
+        assert!("spiral".parse::<ClueSymmetry>().is_err());
+    }
+}
@@ -1,39 +1,103 @@
-use rand::rngs::ThreadRng;
 use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
 
 use crate::io::{show_progress, Cancelable};
 use crate::layout::{Cell, CellSet};
 use crate::puzzle::{Board, Effects};
-use crate::solve::{find_brute_force, Resolution, Solver, Timings};
+use crate::solve::{Resolution, Solver, Technique, Timings, NON_PEER_TECHNIQUES};
+
+use super::{has_unique_solution, ClueSymmetry, Target};
 
 /// Finds a solvable starting puzzle from a full solution.
 pub struct Finder {
     cancelable: Cancelable,
-    rng: ThreadRng,
+    rng: ChaCha8Rng,
     clues: usize,
     time: u64,
+    max_attempts: Option<usize>,
+    validations: Validations,
     bar: bool,
+    symmetry: ClueSymmetry,
+    target: Target,
+    techniques: Vec<Technique>,
 }
 
 impl Finder {
     pub fn new(clues: usize, time: u64, bar: bool) -> Finder {
         Finder {
             cancelable: Cancelable::new(),
-            rng: rand::thread_rng(),
+            rng: ChaCha8Rng::seed_from_u64(rand::random()),
             clues,
             time,
+            max_attempts: None,
+            validations: Validations::default(),
             bar,
+            symmetry: ClueSymmetry::None,
+            target: Target::default(),
+            techniques: NON_PEER_TECHNIQUES.to_vec(),
         }
     }
 
-    pub fn backtracking_find(&mut self, board: Board) -> (Board, Effects) {
-        let solver = Solver::new(false);
+    /// Solves each candidate puzzle using only these techniques.
+    pub fn with_techniques(mut self, techniques: Vec<Technique>) -> Self {
+        self.techniques = techniques;
+        self
+    }
+
+    /// Removes clues in the same order every time for the same seed.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = ChaCha8Rng::seed_from_u64(seed);
+        self
+    }
+
+    /// Stops after checking this many puzzles, which unlike the time limit
+    /// does not depend on the speed of the machine.
+    pub fn with_max_attempts(mut self, max_attempts: Option<usize>) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Returns the number of puzzles checked by the last search.
+    pub fn attempts(&self) -> usize {
+        self.validations.attempts
+    }
+
+    /// Returns how the puzzles checked by the last search were accepted or rejected.
+    pub fn validations(&self) -> Validations {
+        self.validations
+    }
+
+    /// Removes clues in orbits to keep their layout symmetric.
+    pub fn with_symmetry(mut self, symmetry: ClueSymmetry) -> Self {
+        self.symmetry = symmetry;
+        self
+    }
+
+    /// Accepts only puzzles that match the target.
+    pub fn with_target(mut self, target: Target) -> Self {
+        self.target = target;
+        self
+    }
+
+    /// Removes clues from the solution while the puzzle remains solvable,
+    /// returning the matching puzzle with the fewest clues found before time runs out.
+    ///
+    /// The full solution always matches when there is no target,
+    /// so this returns `None` only when no puzzle matched the target.
+    pub fn backtracking_find(&mut self, board: Board) -> Option<(Board, Effects)> {
+        let solver = Solver::new(false).with_techniques(self.techniques.clone());
         let runtime = std::time::Instant::now();
+        let any = self.target.is_any();
 
         let mut timings = Timings::new();
-        let mut fewest_clues = 81;
-        let mut fewest_clues_board = board;
-        let mut fewest_clues_actions = Effects::new();
+        self.validations = Validations::default();
+        let mut fewest_clues = 82;
+        let mut found = None;
+        if any {
+            fewest_clues = 81;
+            found = Some((board, Effects::new()));
+        }
 
         let mut stack = Vec::with_capacity(81);
         stack.push(Entry {
@@ -41,16 +105,29 @@ impl Finder {
             cells: self.shuffle_cells(board.knowns()),
         });
 
-        while !stack.is_empty() {
-            if self.bar {
-                show_progress(82 - stack.len());
-            }
+        loop {
             if self.cancelable.is_canceled()
                 || fewest_clues <= self.clues
                 || runtime.elapsed().as_secs() >= self.time
+                || self
+                    .max_attempts
+                    .is_some_and(|max| self.validations.attempts >= max)
             {
                 break;
             }
+            if stack.is_empty() {
+                // start over with a new order until a match is found
+                if any || found.is_some() {
+                    break;
+                }
+                stack.push(Entry {
+                    board,
+                    cells: self.shuffle_cells(board.knowns()),
+                });
+            }
+            if self.bar {
+                show_progress(82 - stack.len());
+            }
 
             let entry = stack.last_mut().unwrap();
             if entry.cells.is_empty() {
@@ -59,33 +136,47 @@ impl Finder {
             }
 
             let cell = entry.cells.pop().unwrap();
-            let (next, unapplied) = entry.board.without(cell);
+            let orbit = self.symmetry.orbit(cell);
+            let (next, unapplied) = entry.board.with_givens(entry.board.knowns() - orbit);
 
+            self.validations.attempts += 1;
+            if !has_unique_solution(&next) {
+                self.validations.not_unique += 1;
+                continue;
+            }
             match solver.solve(&next, &unapplied, &mut timings) {
                 Resolution::Canceled(..) => break,
                 Resolution::Solved(_, actions, _) => {
-                    if !find_brute_force(&board, false, 0, 2).is_solved() {
+                    if self.target.is_too_hard(&actions) {
+                        self.validations.too_hard += 1;
                         continue;
                     }
-                    if next.known_count() < fewest_clues {
+                    self.validations.accepted += 1;
+                    if next.known_count() < fewest_clues && self.target.matches(&actions) {
                         fewest_clues = next.known_count();
-                        fewest_clues_board = next;
-                        fewest_clues_actions = actions;
+                        found = Some((next, actions));
                     }
                     stack.push(Entry {
                         board: next,
                         cells: self.shuffle_cells(next.knowns()),
                     });
                 }
-                _ => continue,
+                _ => {
+                    self.validations.unsolvable += 1;
+                    continue;
+                }
             }
         }
 
-        (fewest_clues_board, fewest_clues_actions)
+        found
     }
 
+    /// Returns the first cell of each orbit in the set in a random order.
     fn shuffle_cells(&mut self, set: CellSet) -> Vec<Cell> {
-        let mut cells = set.iter().collect::<Vec<Cell>>();
+        let mut cells = set
+            .iter()
+            .filter(|cell| self.symmetry.orbit(*cell).iter().next() == Some(*cell))
+            .collect::<Vec<Cell>>();
 
         cells.shuffle(&mut self.rng);
 
@@ -93,7 +184,78 @@ impl Finder {
     }
 }
 
+/// Counts the puzzles checked while removing clues by how each was accepted or rejected.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Validations {
+    /// Every puzzle checked.
+    pub attempts: usize,
+    /// Puzzles with a single solution that the solver completed.
+    pub accepted: usize,
+    /// Puzzles rejected because brute force found more than one solution.
+    pub not_unique: usize,
+    /// Puzzles with a single solution that the solver could not complete.
+    pub unsolvable: usize,
+    /// Puzzles that needed a strategy harder than the target allows.
+    pub too_hard: usize,
+}
+
 struct Entry {
     board: Board,
     cells: Vec<Cell>,
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::build::Generator;
+    use crate::io::{Parse, Parser};
+    use crate::puzzle::{Changer, Options};
+
+    use super::*;
+
+    #[test]
+    fn same_seed_creates_the_same_puzzle() {
+        let solution = Generator::new(false, false)
+            .with_seed(7)
+            .generate(&Changer::new(Options::all()))
+            .unwrap();
+        assert_eq!(
+            "287531649541896732639742158328465971796318524415927863173684295854279316962153487",
+            solution.packed_string()
+        );
+
+        let mut finder = Finder::new(22, u64::MAX, false)
+            .with_seed(7)
+            .with_max_attempts(Some(100));
+        let (start, _) = finder.backtracking_find(solution).unwrap();
+        assert_eq!(
+            "2.7....4..4.89.......7..1.....4...71.96.1......5.....3......2..8..2......6..5.4..",
+            start.packed_string()
+        );
+    }
+
+    #[test]
+    fn accepts_only_puzzles_with_a_single_solution() {
+        let solution = Parse::packed().parse_simple(
+            "174385962293467158586192734451923876928674315367851249719548623635219487842736591",
+        );
+
+        let mut finder = Finder::new(0, u64::MAX, false)
+            .with_seed(1)
+            .with_max_attempts(Some(60))
+            .with_symmetry(ClueSymmetry::Rotate90);
+        let (start, _) = finder.backtracking_find(solution).unwrap();
+        assert!(has_unique_solution(&start));
+        assert!(ClueSymmetry::Rotate90.is_symmetric(start.knowns()));
+
+        let validations = finder.validations();
+        assert_eq!(60, validations.attempts);
+        assert!(validations.not_unique > 0);
+        assert_eq!(
+            validations.attempts,
+            validations.accepted
+                + validations.not_unique
+                + validations.unsolvable
+                + validations.too_hard
+        );
+    }
+}
@@ -1,5 +1,6 @@
-use rand::rngs::ThreadRng;
 use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
 
 use crate::io::{show_progress, Cancelable};
 use crate::layout::{Cell, Known, KnownSet};
@@ -8,7 +9,7 @@ use crate::solve::find_intersection_removals;
 
 /// Generates a complete puzzle solution.
 pub struct Generator {
-    rng: ThreadRng,
+    rng: ChaCha8Rng,
     shuffle: bool,
     bar: bool,
 }
@@ -18,12 +19,18 @@ impl Generator {
     /// This will take longer and likely solve fewer cells using singles.
     pub fn new(shuffle: bool, bar: bool) -> Generator {
         Generator {
-            rng: rand::thread_rng(),
+            rng: ChaCha8Rng::seed_from_u64(rand::random()),
             shuffle,
             bar,
         }
     }
 
+    /// Generates the same solution every time for the same seed.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = ChaCha8Rng::seed_from_u64(seed);
+        self
+    }
+
     /// Returns a complete solution or a partial solution if canceled.
     pub fn generate(&mut self, changer: &Changer) -> Option<Board> {
         let cancelable = Cancelable::new();
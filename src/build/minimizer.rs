@@ -0,0 +1,183 @@
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use crate::io::Cancelable;
+use crate::layout::{Cell, CellSet};
+use crate::puzzle::{Board, Difficulty, Effects};
+use crate::solve::{
+    find_brute_force, BruteForceResult, Resolution, Solver, Technique, Timings, NON_PEER_TECHNIQUES,
+};
+
+/// Returns the givens that may each be removed on its own
+/// without giving the puzzle a second solution.
+///
+/// A puzzle with a single solution and no redundant clues is minimal.
+pub fn find_redundant_clues(board: &Board) -> CellSet {
+    board
+        .givens()
+        .iter()
+        .filter(|cell| has_unique_solution(&board.without(*cell).0))
+        .collect()
+}
+
+/// Returns true if brute force finds exactly one solution.
+pub fn has_unique_solution(board: &Board) -> bool {
+    matches!(
+        find_brute_force(board, false, 0, 2),
+        BruteForceResult::Solved(_)
+    )
+}
+
+/// Removes redundant clues from a puzzle until it is minimal.
+pub struct Minimizer {
+    cancelable: Cancelable,
+    rng: ChaCha8Rng,
+    tries: usize,
+    hardest: Option<Difficulty>,
+    techniques: Vec<Technique>,
+}
+
+impl Minimizer {
+    pub fn new() -> Self {
+        Self {
+            cancelable: Cancelable::new(),
+            rng: ChaCha8Rng::seed_from_u64(rand::random()),
+            tries: 1,
+            hardest: None,
+            techniques: NON_PEER_TECHNIQUES.to_vec(),
+        }
+    }
+
+    /// Shuffles the clues the same way every time for the same seed.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = ChaCha8Rng::seed_from_u64(seed);
+        self
+    }
+
+    /// Repeats the search with the clues in a new random order,
+    /// keeping the minimal puzzle with the fewest clues.
+    ///
+    /// A single try removes clues in the order of their cells.
+    pub fn with_tries(mut self, tries: usize) -> Self {
+        self.tries = tries.max(1);
+        self
+    }
+
+    /// Keeps only clues whose removal leaves the puzzle solvable
+    /// without a strategy harder than this difficulty.
+    pub fn with_hardest(mut self, hardest: Difficulty) -> Self {
+        self.hardest = Some(hardest);
+        self
+    }
+
+    /// Solves puzzles using only these techniques when checking the difficulty.
+    pub fn with_techniques(mut self, techniques: Vec<Technique>) -> Self {
+        self.techniques = techniques;
+        self
+    }
+
+    /// Returns the puzzle with the fewest clues found by removing one clue
+    /// at a time whenever the puzzle keeps a single solution,
+    /// or `None` if the puzzle does not have a single solution to start.
+    pub fn minimize(&mut self, board: &Board) -> Option<Board> {
+        if !has_unique_solution(board) {
+            return None;
+        }
+
+        let solver = Solver::new(false).with_techniques(self.techniques.clone());
+        let mut timings = Timings::new();
+        let mut best = *board;
+
+        for i in 0..self.tries {
+            if self.cancelable.is_canceled() {
+                break;
+            }
+
+            let mut cells = board.givens().iter().collect::<Vec<Cell>>();
+            if i > 0 {
+                cells.shuffle(&mut self.rng);
+            }
+
+            let mut current = *board;
+            for cell in cells {
+                let (next, unapplied) = current.without(cell);
+                if has_unique_solution(&next)
+                    && self.is_easy_enough(&solver, &next, &unapplied, &mut timings)
+                {
+                    current = next;
+                }
+            }
+
+            if current.known_count() < best.known_count() {
+                best = current;
+            }
+        }
+
+        Some(best)
+    }
+
+    fn is_easy_enough(
+        &self,
+        solver: &Solver,
+        board: &Board,
+        unapplied: &Effects,
+        timings: &mut Timings,
+    ) -> bool {
+        let Some(hardest) = self.hardest else {
+            return true;
+        };
+        match solver.solve(board, unapplied, timings) {
+            Resolution::Solved(_, _, difficulty) => difficulty <= hardest,
+            _ => false,
+        }
+    }
+}
+
+impl Default for Minimizer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::io::{Parse, Parser};
+
+    use super::*;
+
+    #[test]
+    fn finds_redundant_clues() {
+        let parser = Parse::packed();
+        let minimal = parser.parse_simple(
+            "1.......2.9.4...5...6...7...5.9.3.......7.......85..4.7.....6...3...9.8...2.....1",
+        );
+        assert!(find_redundant_clues(&minimal).is_empty());
+
+        // the solution of A2 is implied by the other clues
+        let extra = parser.parse_simple(
+            "17......2.9.4...5...6...7...5.9.3.......7.......85..4.7.....6...3...9.8...2.....1",
+        );
+        assert!(find_redundant_clues(&extra).has(Cell::new(1)));
+    }
+
+    #[test]
+    fn minimizes_to_a_minimal_puzzle() {
+        let parser = Parse::packed();
+        let board = parser.parse_simple(
+            "..2...376.1..3.5.........9.9..85...1...3.4...2...97..3.8.........3.4..6.147...2..",
+        );
+
+        let minimal = Minimizer::new().minimize(&board).unwrap();
+        assert!(has_unique_solution(&minimal));
+        assert!(find_redundant_clues(&minimal).is_empty());
+        assert!(minimal.givens().is_subset_of(board.givens()));
+    }
+
+    #[test]
+    fn rejects_puzzles_without_a_single_solution() {
+        let board = Parse::packed().parse_simple("123456789");
+
+        assert_eq!(None, Minimizer::new().minimize(&board));
+    }
+}
@@ -0,0 +1,177 @@
+use crate::puzzle::{Difficulty, Effects, Strategy};
+use crate::solve::Rating;
+
+/// Describes the puzzle a [`Finder`][`crate::build::Finder`] should seek
+/// based on the actions taken to solve it.
+///
+/// The default target accepts every solvable puzzle.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Target {
+    difficulty: Option<Difficulty>,
+    hardest: Option<Difficulty>,
+    require: Vec<Strategy>,
+    forbid: Vec<Strategy>,
+    min_rating: Option<f32>,
+    max_rating: Option<f32>,
+}
+
+impl Target {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Accepts only puzzles whose hardest strategy has this difficulty.
+    pub fn with_difficulty(mut self, difficulty: Difficulty) -> Self {
+        self.difficulty = Some(difficulty);
+        self
+    }
+
+    /// Rejects puzzles that need a strategy harder than this difficulty.
+    pub fn with_hardest(mut self, hardest: Difficulty) -> Self {
+        self.hardest = Some(hardest);
+        self
+    }
+
+    /// Accepts only puzzles whose solution uses every one of these strategies.
+    pub fn with_required(mut self, strategies: Vec<Strategy>) -> Self {
+        self.require = strategies;
+        self
+    }
+
+    /// Rejects puzzles whose solution uses any of these strategies.
+    pub fn with_forbidden(mut self, strategies: Vec<Strategy>) -> Self {
+        self.forbid = strategies;
+        self
+    }
+
+    /// Accepts only puzzles rated within this range, inclusive.
+    pub fn with_rating(mut self, min: Option<f32>, max: Option<f32>) -> Self {
+        self.min_rating = min;
+        self.max_rating = max;
+        self
+    }
+
+    /// Returns true if every solvable puzzle is accepted.
+    pub fn is_any(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Returns true if the puzzle solved using these actions is accepted.
+    pub fn matches(&self, actions: &Effects) -> bool {
+        let difficulty = actions.difficulty();
+        if self.difficulty.is_some_and(|wanted| wanted != difficulty) {
+            return false;
+        }
+        if self.is_too_hard(actions) {
+            return false;
+        }
+
+        let counts = actions.action_counts();
+        if self
+            .require
+            .iter()
+            .any(|strategy| !counts.contains_key(strategy))
+        {
+            return false;
+        }
+        if self
+            .forbid
+            .iter()
+            .any(|strategy| counts.contains_key(strategy))
+        {
+            return false;
+        }
+
+        let rating = Rating::new(actions).hardest();
+        match self.min_rating {
+            Some(min) => rating >= min,
+            None => true,
+        }
+    }
+
+    /// Returns true if the puzzle exceeds the hardest difficulty or rating allowed.
+    ///
+    /// Removing more clues rarely makes a puzzle easier,
+    /// so there's little point continuing to search from it.
+    pub fn is_too_hard(&self, actions: &Effects) -> bool {
+        let difficulty = actions.difficulty();
+        let cap = match (self.difficulty, self.hardest) {
+            (Some(wanted), Some(hardest)) => Some(wanted.min(hardest)),
+            (wanted, hardest) => wanted.or(hardest),
+        };
+        if cap.is_some_and(|cap| difficulty > cap) {
+            return true;
+        }
+
+        self.max_rating
+            .is_some_and(|max| Rating::new(actions).hardest() > max)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::layout::cells::cell::cell;
+    use crate::layout::values::known::known;
+    use crate::layout::{Cell, Known};
+    use crate::puzzle::Action;
+
+    use super::*;
+
+    fn solved_with(strategies: &[Strategy]) -> Effects {
+        let mut effects = Effects::new();
+        for (i, strategy) in strategies.iter().enumerate() {
+            effects.add_action(Action::new_erase(
+                *strategy,
+                Cell::new(i as u8),
+                known!("5"),
+            ));
+        }
+        effects.add_action(Action::new_set(
+            Strategy::HiddenSingle,
+            cell!("J9"),
+            known!("1"),
+        ));
+        effects
+    }
+
+    #[test]
+    fn accepts_everything_by_default() {
+        let target = Target::new();
+
+        assert!(target.is_any());
+        assert!(target.matches(&solved_with(&[Strategy::XYChain])));
+    }
+
+    #[test]
+    fn matches_difficulty_exactly() {
+        let target = Target::new().with_difficulty(Difficulty::Tough);
+
+        assert!(target.matches(&solved_with(&[Strategy::NakedPair, Strategy::XWing])));
+        assert!(!target.matches(&solved_with(&[Strategy::NakedPair])));
+        assert!(!target.matches(&solved_with(&[Strategy::XYChain])));
+        assert!(target.is_too_hard(&solved_with(&[Strategy::XYChain])));
+    }
+
+    #[test]
+    fn requires_and_forbids_strategies() {
+        let target = Target::new()
+            .with_required(vec![Strategy::XWing])
+            .with_forbidden(vec![Strategy::YWing]);
+
+        assert!(target.matches(&solved_with(&[Strategy::XWing])));
+        assert!(!target.matches(&solved_with(&[Strategy::Swordfish])));
+        assert!(!target.matches(&solved_with(&[Strategy::XWing, Strategy::YWing])));
+    }
+
+    #[test]
+    fn caps_hardest_difficulty_and_rating() {
+        let target = Target::new()
+            .with_hardest(Difficulty::Tough)
+            .with_rating(Some(3.0), Some(4.0));
+
+        assert!(target.matches(&solved_with(&[Strategy::XWing])));
+        assert!(!target.matches(&solved_with(&[Strategy::PointingPair])));
+        assert!(target.is_too_hard(&solved_with(&[Strategy::YWing])));
+        assert!(target.is_too_hard(&solved_with(&[Strategy::Jellyfish])));
+    }
+}
@@ -1,13 +1,29 @@
 pub use bingo::{bingo, BingoArgs};
+pub use canon::{canonicalize_puzzles, CanonArgs};
 pub use create::{create_puzzle, CreateArgs};
+pub use explain::{explain_puzzle, ExplainArgs};
 pub use extract::{extract_patterns, ExtractArgs};
 pub use find::{find_solutions, FindArgs};
+pub use minimize::{minimize_puzzles, MinimizeArgs};
+pub use output::OutputFormat;
 pub use play::{start_player, PlayArgs};
+pub use serve::{start_server, ServeArgs};
 pub use solve::{solve_puzzles, SolveArgs};
+pub use techniques::TechniqueArgs;
+pub use threads::ThreadArgs;
+pub use transform::{transform_puzzles, TransformArgs};
 
 mod bingo;
+mod canon;
 mod create;
+mod explain;
 mod extract;
 mod find;
+mod minimize;
+mod output;
 mod play;
+mod serve;
 mod solve;
+mod techniques;
+mod threads;
+mod transform;
@@ -1,14 +1,17 @@
-use std::ops::RangeInclusive;
+use std::io::{stdout, BufWriter, Write};
 use std::time::Instant;
 
 use clap::Args;
 
-use crate::io::{
-    format_for_wiki, format_runtime, print_all_and_single_candidates, print_known_values, Parse,
-    Parser, SUDOKUWIKI_URL,
+use sudoku_rust::io::{
+    format_for_wiki, format_number, format_runtime, print_all_and_single_candidates,
+    print_known_values, to_json, BruteForceJson, BruteForceStatus, Parse, Parser,
+    SolutionCountJson, SolutionCountStatus, SUDOKUWIKI_URL,
 };
-use crate::puzzle::{ChangeResult, Changer, Options};
-use crate::solve::{find_brute_force, BruteForceResult};
+use sudoku_rust::puzzle::{Board, ChangeResult, Changer, Options};
+use sudoku_rust::solve::{count_solutions, find_brute_force, BruteForceResult, SolutionCount};
+
+use super::OutputFormat;
 
 #[derive(Debug, Args)]
 pub struct BingoArgs {
@@ -21,8 +24,24 @@ pub struct BingoArgs {
     pause: u32,
 
     /// Maximum number of solutions to find before stopping
-    #[clap(short, long, default_value = "100", value_parser = max_solutions_in_range)]
-    max: usize,
+    ///
+    /// Defaults to 100 when listing solutions and no limit when counting them.
+    #[clap(short, long, value_parser = parse_max_solutions)]
+    max: Option<usize>,
+
+    /// Count the solutions without storing them
+    #[clap(short, long, conflicts_with = "log")]
+    count: bool,
+
+    /// Print each solution in packed format as it is found
+    ///
+    /// This implies `--count` and prints the number of solutions to stderr.
+    #[clap(short, long, conflicts_with_all = ["log", "format"])]
+    stream: bool,
+
+    /// Print the solutions as JSON instead of boards
+    #[clap(short, long, value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
 
     /// Clues for a puzzle to solve using Bowman's Bingo
     puzzle: String,
@@ -30,10 +49,61 @@ pub struct BingoArgs {
 
 /// Creates a new puzzle and prints it to stdout.
 pub fn bingo(args: BingoArgs) {
+    let counting = args.count || args.stream;
+    if !counting && args.max.is_some_and(|max| max > MAX_LISTED_SOLUTIONS) {
+        eprintln!(
+            "--max must be at most {} unless counting solutions",
+            format_number(MAX_LISTED_SOLUTIONS as u128)
+        );
+        return;
+    }
+    let max = args.max.unwrap_or(if counting {
+        usize::MAX
+    } else {
+        DEFAULT_MAX_SOLUTIONS
+    });
+
     let changer = Changer::new(Options::none());
     let parser = Parse::packed_with_player(changer);
 
     let (mut board, effects, failure) = parser.parse(&args.puzzle);
+    let invalid = failure.is_some()
+        || matches!(
+            changer.apply_all(&board, &effects),
+            ChangeResult::Invalid(..)
+        );
+    if args.stream {
+        stream_solutions(&board, invalid, max);
+        return;
+    }
+
+    if args.format.is_json() {
+        if args.count {
+            let result = if invalid {
+                SolutionCountJson {
+                    status: SolutionCountStatus::Invalid,
+                    count: 0,
+                }
+            } else {
+                SolutionCountJson::new(count_solutions(&board, max, |_| ()))
+            };
+            println!("{}", to_json(&result));
+            return;
+        }
+
+        let result = if invalid {
+            BruteForceJson {
+                status: BruteForceStatus::Invalid,
+                solutions: vec![],
+                empty_cells: vec![],
+            }
+        } else {
+            BruteForceJson::new(&board, &find_brute_force(&board, false, 0, max))
+        };
+        println!("{}", to_json(&result));
+        return;
+    }
+
     if !board.is_fully_solved() {
         print_all_and_single_candidates(&board);
         println!("\n=> {}{}", SUDOKUWIKI_URL, format_for_wiki(&board));
@@ -53,8 +123,18 @@ pub fn bingo(args: BingoArgs) {
     }
 
     let runtime = Instant::now();
+    if args.count {
+        let count = count_solutions(&board, max, |_| ());
+        println!(
+            "\n{} in {} µs",
+            describe_count(count),
+            format_runtime(runtime.elapsed())
+        );
+        return;
+    }
+
     let (label, empty_cells, solution, solutions) =
-        match find_brute_force(&board, args.log, args.pause, args.max) {
+        match find_brute_force(&board, args.log, args.pause, max) {
             BruteForceResult::AlreadySolved => ("already solved in".to_string(), None, None, None),
             BruteForceResult::TooFewKnowns => {
                 ("not enough givens in".to_string(), None, None, None)
@@ -100,20 +180,51 @@ pub fn bingo(args: BingoArgs) {
     }
 }
 
-const MAX_SOLUTIONS_RANGE: RangeInclusive<usize> = 1..=1_000_000;
+/// Prints each solution on its own line as it is found,
+/// leaving stdout free of everything else.
+fn stream_solutions(board: &Board, invalid: bool, max: usize) {
+    if invalid {
+        eprintln!("invalid puzzle");
+        return;
+    }
+
+    let runtime = Instant::now();
+    let mut out = BufWriter::new(stdout().lock());
+    let mut line = [b'\n'; 82];
+    let count = count_solutions(board, max, |digits| {
+        for (c, digit) in line.iter_mut().zip(digits) {
+            *c = b'0' + digit;
+        }
+        out.write_all(&line).ok();
+    });
+    out.flush().ok();
+    eprintln!(
+        "{} in {} µs",
+        describe_count(count),
+        format_runtime(runtime.elapsed())
+    );
+}
+
+fn describe_count(count: SolutionCount) -> String {
+    let solutions = format!(
+        "{} solution{}",
+        format_number(count.count() as u128),
+        if count.count() == 1 { "" } else { "s" }
+    );
+    match count {
+        SolutionCount::Exact(_) => format!("found {}", solutions),
+        SolutionCount::AtLeast(_) => format!("found at least {}", solutions),
+        SolutionCount::Canceled(_) => format!("canceled after finding {}", solutions),
+    }
+}
 
-fn max_solutions_in_range(s: &str) -> Result<usize, String> {
-    let max: usize = s
-        .replace(',', "")
-        .parse()
-        .map_err(|_| format!("`{}` must be an integer", s))?;
-    if MAX_SOLUTIONS_RANGE.contains(&max) {
-        Ok(max)
-    } else {
-        Err(format!(
-            "must be in range {}-{}",
-            MAX_SOLUTIONS_RANGE.start(),
-            MAX_SOLUTIONS_RANGE.end()
-        ))
+const DEFAULT_MAX_SOLUTIONS: usize = 100;
+const MAX_LISTED_SOLUTIONS: usize = 1_000_000;
+
+fn parse_max_solutions(s: &str) -> Result<usize, String> {
+    match s.replace(',', "").parse() {
+        Ok(0) => Err("must be at least 1".to_string()),
+        Ok(max) => Ok(max),
+        Err(_) => Err(format!("`{}` must be an integer", s)),
     }
 }
@@ -0,0 +1,84 @@
+use std::collections::HashMap;
+use std::io::BufRead;
+use std::time::Instant;
+
+use clap::Args;
+use itertools::Itertools;
+
+use sudoku_rust::io::{format_number, format_runtime, Cancelable, Parse, Parser};
+use sudoku_rust::layout::CellSet;
+use sudoku_rust::puzzle::{canonical_board, canonical_pattern, Options};
+
+#[derive(Debug, Args)]
+pub struct CanonArgs {
+    /// Print each essentially different puzzle once with the number of isomorphs found
+    #[clap(short, long)]
+    group: bool,
+
+    /// Canonicalize the pattern of givens instead of the digits
+    #[clap(short, long)]
+    pattern: bool,
+}
+
+/// Prints the canonical form of each puzzle from STDIN
+/// or counts the essentially different puzzles.
+pub fn canonicalize_puzzles(args: CanonArgs) {
+    let cancelable = Cancelable::new();
+    let runtime = Instant::now();
+    let parser = Parse::packed_with_options(Options::errors());
+    let stdin = std::io::stdin();
+
+    let mut groups: HashMap<String, (usize, usize)> = HashMap::new();
+    let mut count: usize = 0;
+
+    for puzzle in stdin.lock().lines().map_while(Result::ok) {
+        if puzzle.trim().is_empty() {
+            continue;
+        }
+        let canonical = if args.pattern {
+            canonical_pattern(CellSet::new_from_pattern(&puzzle)).pattern_string()
+        } else {
+            let (board, _, failure) = parser.parse(&puzzle);
+            if failure.is_some() {
+                eprintln!("{} - invalid", puzzle);
+                continue;
+            }
+            canonical_board(&board).packed_string()
+        };
+
+        if args.group {
+            let next = groups.len();
+            groups.entry(canonical).or_insert((next, 0)).1 += 1;
+        } else {
+            println!("{}", canonical);
+        }
+        count += 1;
+        if cancelable.is_canceled() {
+            break;
+        }
+    }
+
+    if !args.group {
+        return;
+    }
+    if count == 0 {
+        println!("no puzzles found");
+        return;
+    }
+
+    let group_count = groups.len();
+    for (canonical, (_, isomorphs)) in groups
+        .into_iter()
+        .sorted_by(|a, b| b.1 .1.cmp(&a.1 .1).then(a.1 .0.cmp(&b.1 .0)))
+    {
+        println!("{} - {}", canonical, format_number(isomorphs as u128));
+    }
+
+    println!(
+        "\nfound {} {} ({} essentially different) in {} µs",
+        format_number(count as u128),
+        if args.pattern { "patterns" } else { "puzzles" },
+        format_number(group_count as u128),
+        format_runtime(runtime.elapsed())
+    );
+}
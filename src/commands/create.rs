@@ -4,11 +4,18 @@ use std::time::Instant;
 use clap::Args;
 use itertools::Itertools;
 
-use crate::build::{Finder, Generator};
-use crate::io::{
-    format_runtime, print_all_and_single_candidates, print_known_values, Cancelable, Parse, Parser,
+use sudoku_rust::build::{ClueSymmetry, Finder, Generator, Target, Validations};
+use sudoku_rust::io::{
+    format_number, format_runtime, print_all_and_single_candidates, print_known_values, to_json,
+    Cancelable, Parse, Parser, PuzzleJson,
 };
-use crate::puzzle::{Changer, Options};
+use sudoku_rust::puzzle::{Changer, Difficulty, Options, Strategy};
+use sudoku_rust::solve::Rating;
+
+use super::{OutputFormat, TechniqueArgs};
+
+/// Limits a seeded search by attempts so it finishes the same way on any machine.
+pub const SEEDED_ATTEMPTS: usize = 100;
 
 #[derive(Debug, Args)]
 pub struct CreateArgs {
@@ -20,10 +27,26 @@ pub struct CreateArgs {
     #[clap(short, long)]
     clues: Option<usize>,
 
-    /// Stop after the given number of seconds
+    /// Stop after the given number of seconds (unlimited with a seed)
     #[clap(short, long)]
     time: Option<u64>,
 
+    /// Seed the random generator to create the same puzzle every time
+    #[clap(long)]
+    seed: Option<u64>,
+
+    /// Stop after trying the given number of puzzles (defaults to 100 with a seed)
+    #[clap(long)]
+    attempts: Option<usize>,
+
+    /// Keep the clues symmetric: none, 180, 90, horizontal, vertical or diagonal
+    #[clap(long, default_value_t = ClueSymmetry::None)]
+    symmetry: ClueSymmetry,
+
+    /// Report how many puzzles were rejected for multiple solutions or being unsolvable
+    #[clap(short, long)]
+    verify: bool,
+
     /// Show a progress bar while running
     #[clap(short, long)]
     bar: bool,
@@ -31,12 +54,45 @@ pub struct CreateArgs {
     /// The completed puzzle to use as a starting point
     #[clap(short, long)]
     solution: Option<String>,
+
+    /// Print the puzzle and its solution path as JSON instead of boards
+    #[clap(short, long, value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
+
+    /// Accept only puzzles whose hardest strategy has this difficulty
+    #[clap(long)]
+    difficulty: Option<Difficulty>,
+
+    /// Reject puzzles that need a strategy harder than this difficulty
+    #[clap(long)]
+    hardest: Option<Difficulty>,
+
+    /// Accept only puzzles that need all of these strategies
+    #[clap(long, value_delimiter = ',')]
+    require: Vec<Strategy>,
+
+    /// Reject puzzles that use any of these strategies
+    #[clap(long, value_delimiter = ',')]
+    forbid: Vec<Strategy>,
+
+    /// Accept only puzzles rated at least this high
+    #[clap(long)]
+    min_rating: Option<f32>,
+
+    /// Accept only puzzles rated at most this high
+    #[clap(long)]
+    max_rating: Option<f32>,
+
+    #[clap(flatten)]
+    techniques: TechniqueArgs,
 }
 
 /// Creates a new puzzle and prints it to stdout,
 /// using the given solution and/or pattern if provided.
 pub fn create_puzzle(args: CreateArgs) {
     let cancelable = Cancelable::new();
+    let json = args.format.is_json();
+    let seed = args.seed.unwrap_or_else(rand::random);
     let board = match args.solution {
         Some(solution) => {
             let parser = Parse::packed_with_options(Options::all());
@@ -58,7 +114,7 @@ pub fn create_puzzle(args: CreateArgs) {
         }
         None => {
             let changer = Changer::new(Options::all());
-            let mut generator = Generator::new(args.randomize, args.bar);
+            let mut generator = Generator::new(args.randomize, args.bar && !json).with_seed(seed);
 
             match generator.generate(&changer) {
                 Some(board) => {
@@ -83,24 +139,84 @@ pub fn create_puzzle(args: CreateArgs) {
         }
     };
 
-    print_known_values(&board);
-    println!(
-        "\n==> Seeking a starting puzzle for {} ...",
-        board.packed_string()
-    );
+    if !json {
+        print_known_values(&board);
+        println!(
+            "\n==> Seeking a starting puzzle for {} ...",
+            board.packed_string()
+        );
+    }
+
+    let techniques = args.techniques.techniques();
+    let mut target = Target::new()
+        .with_required(args.require)
+        .with_forbidden(args.forbid)
+        .with_rating(args.min_rating, args.max_rating);
+    if let Some(difficulty) = args.difficulty {
+        target = target.with_difficulty(difficulty);
+    }
+    if let Some(hardest) = args.hardest {
+        target = target.with_hardest(hardest);
+    }
+
+    // a time limit would make a seeded search depend on the speed of the machine
+    let (time, attempts) = match args.seed {
+        Some(_) => (
+            args.time.unwrap_or(u64::MAX),
+            Some(args.attempts.unwrap_or(SEEDED_ATTEMPTS)),
+        ),
+        None => (args.time.unwrap_or(10), args.attempts),
+    };
 
     let runtime = Instant::now();
-    let mut finder = Finder::new(args.clues.unwrap_or(22), args.time.unwrap_or(10), args.bar);
-    let (start, actions) = finder.backtracking_find(board);
+    let mut finder = Finder::new(args.clues.unwrap_or(22), time, args.bar && !json)
+        .with_seed(seed)
+        .with_max_attempts(attempts)
+        .with_symmetry(args.symmetry)
+        .with_target(target)
+        .with_techniques(techniques);
+    let Some((start, actions)) = finder.backtracking_find(board) else {
+        if args.verify {
+            print_validations(finder.validations());
+        }
+        eprintln!(
+            "\n==> No matching puzzle found in {} µs",
+            format_runtime(runtime.elapsed())
+        );
+        exit(1);
+    };
+
+    if json {
+        println!(
+            "{}",
+            to_json(
+                &PuzzleJson::new(&start, &board, actions.difficulty(), &actions).with_seed(seed)
+            )
+        );
+        if args.verify {
+            print_validations(finder.validations());
+        }
+        return;
+    }
 
     println!();
     print_all_and_single_candidates(&start);
     println!(
-        "\n==> Created puzzle with {} clues in {} µs\n\n    {}\n",
+        "\n==> Created {:?} puzzle rated {} with {} clues in {} µs from seed {}\n\n    {}\n",
+        actions.difficulty(),
+        Rating::new(&actions),
         start.known_count(),
         format_runtime(runtime.elapsed()),
+        seed,
         start.packed_string()
     );
+    if args.seed.is_none() {
+        println!(
+            "    Recreate it with --seed {} --attempts {}\n",
+            seed,
+            finder.attempts()
+        );
+    }
 
     let counts = actions.action_counts();
     counts
@@ -109,4 +225,22 @@ pub fn create_puzzle(args: CreateArgs) {
         .for_each(|(strategy, count)| {
             println!("- {:>2} {:?}", count, strategy);
         });
+
+    if args.verify {
+        print_validations(finder.validations());
+    }
+}
+
+/// Prints how the puzzles checked while removing clues were accepted or rejected
+/// to STDERR to keep it apart from any JSON output.
+fn print_validations(validations: Validations) {
+    let count = |count: usize| format_number(count as u128);
+    eprintln!(
+        "\n==> Checked {} puzzles\n\n    - {} accepted\n    - {} rejected with more than one solution\n    - {} rejected as unsolvable by the chosen techniques\n    - {} rejected as too hard",
+        count(validations.attempts),
+        count(validations.accepted),
+        count(validations.not_unique),
+        count(validations.unsolvable),
+        count(validations.too_hard),
+    );
 }
@@ -0,0 +1,106 @@
+use std::process::exit;
+
+use clap::Args;
+
+use sudoku_rust::io::{
+    explain, format_for_wiki, print_all_and_single_candidates,
+    print_all_and_single_candidates_with_highlight, Parse, Parser, SUDOKUWIKI_URL,
+};
+use sudoku_rust::puzzle::{Board, ChangeResult, Changer, Effects, Options};
+use sudoku_rust::solve::{Rating, Resolution, Solver, Timings};
+
+use super::TechniqueArgs;
+
+#[derive(Debug, Args)]
+pub struct ExplainArgs {
+    /// Print only the explanations without the boards
+    #[clap(short, long)]
+    brief: bool,
+
+    #[clap(flatten)]
+    techniques: TechniqueArgs,
+
+    /// Clues for the puzzle to explain
+    puzzle: String,
+}
+
+/// Solves the puzzle and explains each step with the board highlighted.
+pub fn explain_puzzle(args: ExplainArgs) {
+    let changer = Changer::new(Options::errors());
+    let parser = Parse::packed_with_player(changer);
+    let (start, effects, failure) = parser.parse(&args.puzzle);
+
+    if let Some((cell, known)) = failure {
+        print_all_and_single_candidates(&start);
+        eprintln!("\n==> Setting {} to {} will cause errors\n", cell, known);
+        effects.print_errors();
+        exit(1);
+    }
+
+    let solver = Solver::new(false).with_techniques(args.techniques.techniques());
+    let resolution = solver.solve(&start, &effects, &mut Timings::new());
+    let applied = match &resolution {
+        Resolution::Canceled(_, applied, _)
+        | Resolution::Failed(_, applied, ..)
+        | Resolution::Unsolved(_, applied, _)
+        | Resolution::Solved(_, applied, _) => applied,
+    };
+
+    let board = explain_steps(&start, applied, args.brief);
+
+    match resolution {
+        Resolution::Canceled(..) => println!("==> Canceled"),
+        Resolution::Failed(_, _, _, action, errors) => {
+            println!("==> {}\n", explain(&action));
+            print_all_and_single_candidates_with_highlight(&board, &action);
+            println!("\n==> This causes errors\n");
+            errors.print_errors();
+        }
+        Resolution::Unsolved(..) => {
+            print_all_and_single_candidates(&board);
+            println!(
+                "\n==> Unable to continue with the available techniques - {}{}",
+                SUDOKUWIKI_URL,
+                format_for_wiki(&board)
+            );
+        }
+        Resolution::Solved(solution, actions, difficulty) => {
+            if !args.brief {
+                print_all_and_single_candidates(&solution);
+                println!();
+            }
+            println!(
+                "==> Solved {:?} puzzle rated {} in {} steps",
+                difficulty,
+                Rating::new(&actions),
+                actions.action_count()
+            );
+        }
+    }
+}
+
+/// Prints each action before applying it and returns the final board.
+fn explain_steps(start: &Board, applied: &Effects, brief: bool) -> Board {
+    let changer = Changer::new(Options::errors());
+    let mut board = *start;
+
+    for (step, action) in applied.actions().iter().enumerate() {
+        println!("{:>3}. {}", step + 1, explain(action));
+        if !brief {
+            println!();
+            print_all_and_single_candidates_with_highlight(&board, action);
+            println!();
+        }
+
+        match changer.apply(&board, action) {
+            ChangeResult::Valid(after, _) => board = *after,
+            ChangeResult::None => (),
+            ChangeResult::Invalid(..) => break,
+        }
+    }
+
+    if brief {
+        println!();
+    }
+    board
+}
@@ -5,8 +5,8 @@ use std::time::Instant;
 use clap::Args;
 use itertools::Itertools;
 
-use crate::io::{format_number, format_runtime, Cancelable};
-use crate::layout::CellSet;
+use sudoku_rust::io::{format_number, format_runtime, Cancelable};
+use sudoku_rust::layout::CellSet;
 
 #[derive(Debug, Args)]
 pub struct ExtractArgs {
@@ -2,18 +2,22 @@ use std::io::{stdin, BufRead};
 use std::process::exit;
 use std::sync::mpsc::{channel, Receiver};
 use std::sync::{Arc, Mutex};
-use std::thread::{available_parallelism, spawn};
+use std::thread::spawn;
 use std::time::Instant;
 
 use clap::Args;
 use itertools::Itertools;
 
-use crate::io::{
-    format_number, format_runtime, print_all_and_single_candidates, Cancelable, Parse, Parser,
+use sudoku_rust::build::{has_unique_solution, Validations};
+use sudoku_rust::io::{
+    format_number, format_runtime, print_all_and_single_candidates, to_json, Cancelable, Parse,
+    Parser, PuzzleJson,
 };
-use crate::layout::CellSet;
-use crate::puzzle::{Board, Changer, Difficulty, Effects, Options};
-use crate::solve::{Resolution, Solver, Timings};
+use sudoku_rust::layout::CellSet;
+use sudoku_rust::puzzle::{Board, Changer, Difficulty, Effects, Options};
+use sudoku_rust::solve::{Resolution, Solver, Timings};
+
+use super::{OutputFormat, TechniqueArgs, ThreadArgs};
 
 #[derive(Debug, Args)]
 pub struct FindArgs {
@@ -21,9 +25,15 @@ pub struct FindArgs {
     #[clap(short, long)]
     actions: bool,
 
-    /// Worker thread count; negative values are relative to core count
-    #[clap(short, long)]
-    threads: Option<isize>,
+    #[clap(flatten)]
+    threads: ThreadArgs,
+
+    /// Print each solvable puzzle as JSON without the summaries
+    #[clap(short, long, value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
+
+    #[clap(flatten)]
+    techniques: TechniqueArgs,
 
     /// The completed puzzle to use as a starting point
     solution: String,
@@ -33,7 +43,9 @@ pub struct FindArgs {
 pub fn find_solutions(args: FindArgs) {
     let runtime = Instant::now();
     let board = parse_puzzle_or_exit(args.solution);
-    let num_workers = determine_worker_count(args.threads);
+    let num_workers = args.threads.worker_count();
+    let json = args.format.is_json();
+    let techniques = args.techniques.techniques();
 
     // Create channels for sending and receiving strings
     let (pattern_tx, pattern_rx) = channel();
@@ -47,9 +59,10 @@ pub fn find_solutions(args: FindArgs) {
     for id in 1..=num_workers {
         let pattern_rx = pattern_rx.clone();
         let result_tx = result_tx.clone();
+        let techniques = techniques.clone();
         workers.push(spawn(move || {
             let cancelable = Cancelable::new();
-            let solver = Solver::new(false);
+            let solver = Solver::new(false).with_techniques(techniques);
             let runtime = Instant::now();
             let mut count = 0;
             let mut timings = Timings::new();
@@ -62,6 +75,13 @@ pub fn find_solutions(args: FindArgs) {
                 let pattern = pattern.unwrap().to_owned();
 
                 let (start, effects) = board.with_givens(CellSet::new_from_pattern(&pattern));
+                if !has_unique_solution(&start) {
+                    result_tx
+                        .send(PatternResult::NotUnique(pattern, start))
+                        .unwrap();
+                    count += 1;
+                    continue;
+                }
                 match solver.solve(&start, &effects, &mut timings) {
                     Resolution::Canceled(..) => break,
                     Resolution::Solved(_, actions, difficulty) => {
@@ -79,6 +99,9 @@ pub fn find_solutions(args: FindArgs) {
                 count += 1;
             }
 
+            if json {
+                return;
+            }
             println!(
                 "{} processed {} patterns in {} µs - {} p/s",
                 id,
@@ -106,8 +129,7 @@ pub fn find_solutions(args: FindArgs) {
         drop(pattern_tx);
     });
 
-    let mut count = 0;
-    let mut solved = 0;
+    let mut validations = Validations::default();
     let mut easiest = None;
     let mut easiest_counts = 10000;
     let mut hardest = None;
@@ -120,10 +142,20 @@ pub fn find_solutions(args: FindArgs) {
             break;
         }
 
-        count += 1;
+        validations.attempts += 1;
         match processed {
             PatternResult::Success(_, start, actions, difficulty) => {
-                solved += 1;
+                validations.accepted += 1;
+                if json {
+                    let puzzle = PuzzleJson::new(&start, &board, difficulty, &actions);
+                    let puzzle = if args.actions {
+                        puzzle
+                    } else {
+                        puzzle.without_actions()
+                    };
+                    println!("{}", to_json(&puzzle));
+                    continue;
+                }
                 println!("{} {:?}", start.packed_string(), difficulty);
 
                 let action_count = actions.action_count();
@@ -146,7 +178,8 @@ pub fn find_solutions(args: FindArgs) {
                         });
                 }
             }
-            PatternResult::Failure(..) => (),
+            PatternResult::NotUnique(..) => validations.not_unique += 1,
+            PatternResult::Failure(..) => validations.unsolvable += 1,
         }
     }
 
@@ -155,38 +188,28 @@ pub fn find_solutions(args: FindArgs) {
         worker.join().unwrap();
     }
 
-    if count > 0 {
+    if validations.attempts > 0 && !json {
         println!(
             "\n==> Found {} solvable puzzles from {} patterns in {} µs\n",
-            format_number(solved),
-            format_number(count),
+            format_number(validations.accepted as u128),
+            format_number(validations.attempts as u128),
             format_runtime(runtime.elapsed()),
         );
         println!(
-            "    Easiest: {} - {} actions\n    Hardest: {} - {} actions",
-            easiest.unwrap().packed_string(),
-            easiest_counts,
-            hardest.unwrap().packed_string(),
-            hardest_counts,
+            "    Rejected {} with more than one solution and {} as unsolvable\n",
+            format_number(validations.not_unique as u128),
+            format_number(validations.unsolvable as u128),
         );
-    }
-}
-
-fn determine_worker_count(requested: Option<isize>) -> usize {
-    let num_cores = available_parallelism().unwrap().get() as isize;
-    let count = if let Some(count) = requested {
-        if count < 0 {
-            num_cores + count
-        } else {
-            count
+        // every pattern may have been rejected now that uniqueness is checked
+        if let (Some(easiest), Some(hardest)) = (easiest, hardest) {
+            println!(
+                "    Easiest: {} - {} actions\n    Hardest: {} - {} actions",
+                easiest.packed_string(),
+                easiest_counts,
+                hardest.packed_string(),
+                hardest_counts,
+            );
         }
-    } else {
-        num_cores - 1
-    };
-    if count < 1 {
-        1
-    } else {
-        count as usize
     }
 }
 
@@ -212,5 +235,6 @@ fn parse_puzzle_or_exit(solution: String) -> Board {
 
 enum PatternResult {
     Success(String, Board, Effects, Difficulty),
+    NotUnique(String, Board),
     Failure(String, Board),
 }
@@ -0,0 +1,149 @@
+use std::io::BufRead;
+use std::time::Instant;
+
+use clap::Args;
+
+use sudoku_rust::build::{find_redundant_clues, has_unique_solution, Minimizer};
+use sudoku_rust::io::{format_runtime, Cancelable, Parse, Parser};
+use sudoku_rust::puzzle::{Board, Difficulty, Options};
+
+use super::TechniqueArgs;
+
+#[derive(Debug, Args)]
+pub struct MinimizeArgs {
+    /// Only report the redundant clues without removing them
+    #[clap(short, long)]
+    check: bool,
+
+    /// Keep clues whose removal would need a strategy harder than this difficulty
+    #[clap(long)]
+    hardest: Option<Difficulty>,
+
+    /// Repeat with the clues in a random order, keeping the fewest clues found
+    #[clap(short, long, default_value_t = 1)]
+    tries: usize,
+
+    /// Seed the random order of the clues for repeated tries
+    #[clap(long)]
+    seed: Option<u64>,
+
+    #[clap(flatten)]
+    techniques: TechniqueArgs,
+
+    /// Clues for the puzzles to minimize instead of reading them from STDIN
+    puzzles: Option<Vec<String>>,
+}
+
+/// Reports the redundant clues of each puzzle and removes them
+/// to leave a minimal puzzle with the same solution.
+pub fn minimize_puzzles(args: MinimizeArgs) {
+    let cancelable = Cancelable::new();
+    let parser = Parse::packed_with_options(Options::errors());
+    let mut minimizer = Minimizer::new()
+        .with_seed(args.seed.unwrap_or_else(rand::random))
+        .with_tries(args.tries)
+        .with_techniques(args.techniques.techniques());
+    if let Some(hardest) = args.hardest {
+        minimizer = minimizer.with_hardest(hardest);
+    }
+
+    // puzzles from STDIN are reported on one line each
+    let one_line = args.puzzles.is_none();
+    let mut process = |puzzle: &str| {
+        let Some(board) = parse(&parser, puzzle) else {
+            return;
+        };
+        if args.check {
+            describe_redundant_clues(&board, one_line);
+        } else {
+            describe_minimized(&mut minimizer, &board, one_line);
+        }
+    };
+
+    match args.puzzles {
+        Some(puzzles) => {
+            for puzzle in puzzles {
+                process(&puzzle);
+            }
+        }
+        None => {
+            for puzzle in std::io::stdin().lock().lines().map_while(Result::ok) {
+                if cancelable.is_canceled() {
+                    break;
+                }
+                if !puzzle.trim().is_empty() {
+                    process(&puzzle);
+                }
+            }
+        }
+    }
+}
+
+fn parse(parser: &impl Parser, puzzle: &str) -> Option<Board> {
+    let (board, _, failure) = parser.parse(puzzle);
+    if let Some((cell, known)) = failure {
+        eprintln!("{} - setting {} to {} causes errors", puzzle, cell, known);
+        return None;
+    }
+    Some(board)
+}
+
+fn describe_redundant_clues(board: &Board, one_line: bool) {
+    let redundant = has_unique_solution(board).then(|| find_redundant_clues(board));
+    if one_line {
+        match redundant {
+            None => println!("{} - not unique", board.packed_string()),
+            Some(redundant) if redundant.is_empty() => {
+                println!("{} - minimal", board.packed_string())
+            }
+            Some(redundant) => println!(
+                "{} - {} {}",
+                board.packed_string(),
+                redundant.len(),
+                redundant
+            ),
+        }
+        return;
+    }
+
+    println!("\n==> {}\n", board.packed_string());
+    match redundant {
+        None => println!("    Does not have a single solution"),
+        Some(redundant) if redundant.is_empty() => {
+            println!("    Minimal with {} clues", board.known_count())
+        }
+        Some(redundant) => println!(
+            "    {} of {} clues may be removed: {}",
+            redundant.len(),
+            board.known_count(),
+            redundant
+        ),
+    }
+}
+
+fn describe_minimized(minimizer: &mut Minimizer, board: &Board, one_line: bool) {
+    let runtime = Instant::now();
+    let minimal = minimizer.minimize(board);
+    if one_line {
+        match minimal {
+            Some(minimal) => println!("{}", minimal.packed_string()),
+            None => eprintln!("{} - not unique", board.packed_string()),
+        }
+        return;
+    }
+
+    println!("\n==> {}\n", board.packed_string());
+    match minimal {
+        None => println!("    Does not have a single solution"),
+        Some(minimal) if minimal.known_count() == board.known_count() => {
+            println!("    Kept all {} clues", board.known_count())
+        }
+        Some(minimal) => println!(
+            "    Removed {} of {} clues in {} µs\n\n    {}",
+            board.known_count() - minimal.known_count(),
+            board.known_count(),
+            format_runtime(runtime.elapsed()),
+            minimal.packed_string()
+        ),
+    }
+}
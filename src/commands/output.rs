@@ -0,0 +1,17 @@
+use clap::ValueEnum;
+
+/// Selects how a command reports its results.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub enum OutputFormat {
+    /// Boards and tables for reading in a terminal
+    #[default]
+    Text,
+    /// One versioned JSON document per line
+    Json,
+}
+
+impl OutputFormat {
+    pub fn is_json(&self) -> bool {
+        matches!(self, OutputFormat::Json)
+    }
+}
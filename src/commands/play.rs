@@ -5,16 +5,18 @@ use std::time::Instant;
 
 use clap::Args;
 
-use crate::build::{Finder, Generator};
-use crate::io::{
+use sudoku_rust::build::{Finder, Generator};
+use sudoku_rust::io::{
     format_for_fancy_console, format_for_wiki, format_grid, format_packed, format_runtime,
     print_all_and_single_candidates, print_all_and_single_candidates_with_highlight,
     print_candidate, print_givens, print_known_values, Cancelable, Parse, Parser, SUDOKUWIKI_URL,
 };
-use crate::layout::{Cell, CellSet, Known, KnownSet};
-use crate::puzzle::{Board, ChangeResult, Changer, Effects, Options, Strategy};
-use crate::solve::{find_brute_force, BruteForceResult, TECHNIQUES};
-use crate::symbols::{MISSING, UNKNOWN_VALUE};
+use sudoku_rust::layout::{Cell, CellSet, Known, KnownSet};
+use sudoku_rust::puzzle::{Board, ChangeResult, Changer, Effects, Options, Strategy};
+use sudoku_rust::solve::{find_brute_force, BruteForceResult, TECHNIQUES};
+use sudoku_rust::symbols::{MISSING, UNKNOWN_VALUE};
+
+use super::create::SEEDED_ATTEMPTS;
 
 const MAXIMUM_SOLUTIONS: usize = 100;
 
@@ -182,17 +184,41 @@ pub fn start_player(args: PlayArgs) {
                 }
             }
             "C" => {
+                let seeded = match input.get(1) {
+                    Some(seed) => match seed.parse::<u64>() {
+                        Ok(seed) => Some(seed),
+                        Err(_) => {
+                            println!("\n==> C [seed]\n");
+                            continue;
+                        }
+                    },
+                    None => None,
+                };
+                let seed = seeded.unwrap_or_else(rand::random);
+
                 println!();
-                let mut generator = Generator::new(false, true);
-                match generator.generate(&changer) {
+                // generate with every option like create so a seed gives the same puzzle
+                let mut generator = Generator::new(false, true).with_seed(seed);
+                match generator.generate(&Changer::new(Options::all())) {
                     Some(board) => {
-                        let mut finder = Finder::new(22, 10, true);
-                        let (start, _) = finder.backtracking_find(board);
-                        println!("\n==> Clues: {}\n", start);
-                        deductions = None;
-                        highlight = None;
-                        boards.push(start);
-                        show_board = true;
+                        let mut finder = match seeded {
+                            Some(_) => Finder::new(22, u64::MAX, true)
+                                .with_max_attempts(Some(SEEDED_ATTEMPTS)),
+                            None => Finder::new(22, 10, true),
+                        }
+                        .with_seed(seed);
+                        match finder.backtracking_find(board) {
+                            Some((start, _)) => {
+                                println!("\n==> Clues: {} from seed {}\n", start, seed);
+                                deductions = None;
+                                highlight = None;
+                                boards.push(start);
+                                show_board = true;
+                            }
+                            None => {
+                                println!("\n==> Failed to create a new puzzle\n");
+                            }
+                        }
                     }
                     None => {
                         println!("\n==> Failed to create a new puzzle\n");
@@ -699,7 +725,7 @@ fn print_help() {
         "\n",
         "  O [option]          - view or toggle an option\n",
         "  N                   - start or input a new puzzle\n",
-        "  C                   - create a new random puzzle\n",
+        "  C [seed]            - create a new random or seeded puzzle\n",
         "\n",
         "  P [G | K | digit]   - print the full puzzle, givens, knowns, or a single candidate\n",
         "  X [char]            - export the puzzle with optional character for unsolved cells\n",
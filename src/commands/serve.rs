@@ -0,0 +1,391 @@
+use std::fmt::Display;
+use std::io::Read;
+use std::process::exit;
+use std::str::FromStr;
+use std::sync::mpsc::channel;
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+
+use clap::Args;
+use itertools::Itertools;
+use serde::{Deserialize, Deserializer, Serialize};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use sudoku_rust::build::{Finder, Generator, Target};
+use sudoku_rust::io::{
+    to_json, BoardJson, BruteForceJson, Cancelable, EffectsJson, Parse, Parser, PuzzleJson,
+    ResolutionJson,
+};
+use sudoku_rust::puzzle::{Board, Changer, Difficulty, Effects, Options, Strategy};
+use sudoku_rust::solve::{
+    find_brute_force, BruteForceResult, Resolution, Solver, Timings, TECHNIQUES,
+};
+
+use super::ThreadArgs;
+
+/// Caps the time a single create request may spend removing clues.
+const MAXIMUM_CREATE_SECONDS: u64 = 60;
+
+/// Caps the size of a request body.
+const MAXIMUM_BODY_BYTES: u64 = 64 * 1024;
+
+/// Caps the number of solutions returned by a verify request.
+const MAXIMUM_VERIFY_SOLUTIONS: usize = 100;
+
+#[derive(Debug, Args)]
+pub struct ServeArgs {
+    /// The address to listen on
+    #[clap(short, long, default_value = "127.0.0.1")]
+    address: String,
+
+    /// The port to listen on
+    #[clap(short, long, default_value = "8080")]
+    port: u16,
+
+    #[clap(flatten)]
+    threads: ThreadArgs,
+}
+
+/// Starts an HTTP server that creates, solves and verifies puzzles
+/// using JSON request and response bodies until canceled.
+pub fn start_server(args: ServeArgs) {
+    let cancelable = Cancelable::new();
+    let address = format!("{}:{}", args.address, args.port);
+    let server = match Server::http(&address) {
+        Ok(server) => server,
+        Err(error) => {
+            eprintln!("==> Failed to listen on {}: {}", address, error);
+            exit(1);
+        }
+    };
+
+    let num_workers = args.threads.worker_count();
+    println!(
+        "==> Listening on http://{} with {} workers",
+        address, num_workers
+    );
+
+    // a fixed pool keeps long create requests from taking over every core
+    let (request_tx, request_rx) = channel::<Request>();
+    let request_rx = Mutex::new(request_rx);
+
+    thread::scope(|scope| {
+        for _ in 0..num_workers {
+            let request_rx = &request_rx;
+            scope.spawn(move || loop {
+                let request = request_rx.lock().unwrap().recv();
+                match request {
+                    Ok(request) => handle(request),
+                    Err(_) => break,
+                }
+            });
+        }
+
+        while !cancelable.is_canceled() {
+            match server.recv_timeout(Duration::from_millis(250)) {
+                Ok(Some(request)) => request_tx.send(request).unwrap(),
+                Ok(None) => (),
+                Err(error) => {
+                    eprintln!("==> Failed to receive request: {}", error);
+                    break;
+                }
+            }
+        }
+
+        // Close the channel so the workers will stop
+        drop(request_tx);
+    });
+
+    println!("\n==> Server stopped");
+}
+
+/// Routes a single request to its endpoint and sends the JSON response.
+fn handle(mut request: Request) {
+    let mut body = String::new();
+    // read one byte past the limit to tell a full body from a larger one
+    let result = match request
+        .as_reader()
+        .take(MAXIMUM_BODY_BYTES + 1)
+        .read_to_string(&mut body)
+    {
+        Ok(size) if size as u64 > MAXIMUM_BODY_BYTES => Err(ApiError::new(
+            413,
+            format!("Body exceeds {} bytes", MAXIMUM_BODY_BYTES),
+        )),
+        Ok(_) => route(request.method(), request.url(), &body),
+        Err(error) => Err(ApiError::bad_request(format!("Unreadable body: {}", error))),
+    };
+
+    let (status, json) = match result {
+        Ok(json) => (200, json),
+        Err(error) => (
+            error.status,
+            to_json(&ErrorResponse {
+                error: error.message,
+            }),
+        ),
+    };
+
+    let content_type = Header::from_bytes("Content-Type", "application/json").unwrap();
+    let response = Response::from_string(json)
+        .with_status_code(status)
+        .with_header(content_type);
+    if let Err(error) = request.respond(response) {
+        eprintln!("==> Failed to send response: {}", error);
+    }
+}
+
+fn route(method: &Method, url: &str, body: &str) -> Result<String, ApiError> {
+    let path = url.split('?').next().unwrap_or_default();
+    match (method, path) {
+        (Method::Post, "/create") => respond(create(parse_body(body)?)),
+        (Method::Post, "/solve") => respond(solve(parse_body(body)?)),
+        (Method::Post, "/verify") => respond(verify(parse_body(body)?)),
+        (Method::Post, "/hints") => respond(hints(parse_body(body)?)),
+        (_, "/create" | "/solve" | "/verify" | "/hints") => Err(ApiError::new(
+            405,
+            format!("Method {} not allowed for {}", method, path),
+        )),
+        _ => Err(ApiError::new(404, format!("Unknown endpoint {}", path))),
+    }
+}
+
+fn parse_body<'a, T: Deserialize<'a>>(body: &'a str) -> Result<T, ApiError> {
+    let body = if body.trim().is_empty() { "{}" } else { body };
+    serde_json::from_str(body)
+        .map_err(|error| ApiError::bad_request(format!("Invalid JSON: {}", error)))
+}
+
+fn respond<T: Serialize>(result: Result<T, ApiError>) -> Result<String, ApiError> {
+    result.map(|document| to_json(&document))
+}
+
+/// Generates a new solution, unless given one, and removes clues from it.
+fn create(request: CreateRequest) -> Result<PuzzleJson, ApiError> {
+    let solution = match request.solution {
+        Some(solution) => {
+            let board = parse_board(&BoardRequest {
+                puzzle: Some(solution),
+                wiki: None,
+                board: None,
+            })?;
+            if !board.is_fully_solved() {
+                return Err(ApiError::unprocessable(
+                    "You must provide a complete solution".to_string(),
+                ));
+            }
+            board
+        }
+        None => {
+            let changer = Changer::new(Options::all());
+            let mut generator = Generator::new(request.randomize, false);
+            match generator.generate(&changer) {
+                Some(board) if board.is_fully_solved() => board,
+                _ => {
+                    return Err(ApiError::new(
+                        500,
+                        "Failed to generate a complete solution".to_string(),
+                    ))
+                }
+            }
+        }
+    };
+
+    let time = request.time.unwrap_or(10).min(MAXIMUM_CREATE_SECONDS);
+    let mut target = Target::new()
+        .with_required(request.require)
+        .with_forbidden(request.forbid);
+    if let Some(difficulty) = request.difficulty {
+        target = target.with_difficulty(difficulty);
+    }
+    if let Some(hardest) = request.hardest {
+        target = target.with_hardest(hardest);
+    }
+
+    let mut finder = Finder::new(request.clues.unwrap_or(22), time, false).with_target(target);
+    let Some((start, actions)) = finder.backtracking_find(solution) else {
+        return Err(ApiError::unprocessable(
+            "No matching puzzle found in time".to_string(),
+        ));
+    };
+
+    Ok(PuzzleJson::new(
+        &start,
+        &solution,
+        actions.difficulty(),
+        &actions,
+    ))
+}
+
+/// Solves the puzzle one deduction at a time, returning every step taken.
+fn solve(request: BoardRequest) -> Result<ResolutionJson, ApiError> {
+    let start = parse_board(&request)?;
+    let solver = Solver::new(false);
+
+    match solver.solve(&start, &Effects::new(), &mut Timings::new()) {
+        Resolution::Canceled(..) => Err(ApiError::shutting_down()),
+        resolution => Ok(ResolutionJson::new(&resolution)),
+    }
+}
+
+/// Uses brute force to determine if the puzzle has a single solution.
+fn verify(request: VerifyRequest) -> Result<BruteForceJson, ApiError> {
+    let board = parse_board(&request.board)?;
+    let max = request.max.unwrap_or(2).clamp(2, MAXIMUM_VERIFY_SOLUTIONS);
+
+    match find_brute_force(&board, false, 0, max) {
+        BruteForceResult::Canceled => Err(ApiError::shutting_down()),
+        result => Ok(BruteForceJson::new(&board, &result)),
+    }
+}
+
+/// Lists every deduction found by the first technique in solver order that finds any,
+/// skipping the forcing techniques unless requested.
+fn hints(request: HintsRequest) -> Result<EffectsJson, ApiError> {
+    let board = parse_board(&request.board)?;
+    let found = TECHNIQUES
+        .iter()
+        .filter(|technique| request.forcing || !technique.strategy().is_forcing())
+        .find_map(|technique| technique.solve(&board, false))
+        .unwrap_or_default();
+
+    Ok(EffectsJson::new(&found))
+}
+
+/// Builds the board from the JSON board, wiki string or packed givens,
+/// whichever is provided first.
+fn parse_board(request: &BoardRequest) -> Result<Board, ApiError> {
+    if let Some(board) = &request.board {
+        return board.to_board().map_err(ApiError::unprocessable);
+    }
+
+    let (board, effects, failure) = match (&request.wiki, &request.puzzle) {
+        (Some(wiki), _) => Parse::wiki().stop_on_error().parse(wiki),
+        (None, Some(puzzle)) => Parse::packed_with_options(Options::errors()).parse(puzzle),
+        (None, None) => {
+            return Err(ApiError::bad_request(
+                "Provide a board, puzzle or wiki string".to_string(),
+            ))
+        }
+    };
+
+    if let Some((cell, known)) = failure {
+        return Err(ApiError::unprocessable(format!(
+            "Setting {} to {} will cause errors: {}",
+            cell,
+            known,
+            effects
+                .errors_iter()
+                .map(|error| error.to_string())
+                .join(", ")
+        )));
+    }
+
+    Ok(board)
+}
+
+struct ApiError {
+    status: u16,
+    message: String,
+}
+
+impl ApiError {
+    fn new(status: u16, message: String) -> Self {
+        Self { status, message }
+    }
+
+    fn bad_request(message: String) -> Self {
+        Self::new(400, message)
+    }
+
+    fn unprocessable(message: String) -> Self {
+        Self::new(422, message)
+    }
+
+    fn shutting_down() -> Self {
+        Self::new(503, "Server is shutting down".to_string())
+    }
+}
+
+#[derive(Deserialize)]
+struct CreateRequest {
+    /// Stop once a puzzle with this many clues is found
+    clues: Option<usize>,
+    /// Stop after this many seconds
+    time: Option<u64>,
+    /// Randomize the cells before generating the solution
+    #[serde(default)]
+    randomize: bool,
+    /// The completed puzzle to use as a starting point
+    solution: Option<String>,
+    /// Accept only puzzles whose hardest strategy has this difficulty
+    #[serde(default, deserialize_with = "option_from_str")]
+    difficulty: Option<Difficulty>,
+    /// Reject puzzles that need a strategy harder than this difficulty
+    #[serde(default, deserialize_with = "option_from_str")]
+    hardest: Option<Difficulty>,
+    /// Accept only puzzles that need all of these strategies
+    #[serde(default, deserialize_with = "vec_from_str")]
+    require: Vec<Strategy>,
+    /// Reject puzzles that use any of these strategies
+    #[serde(default, deserialize_with = "vec_from_str")]
+    forbid: Vec<Strategy>,
+}
+
+#[derive(Deserialize)]
+struct BoardRequest {
+    /// Packed givens with a digit for each known cell
+    puzzle: Option<String>,
+    /// SudokuWiki string with the full board state including candidates
+    wiki: Option<String>,
+    /// Full board state as produced by the other endpoints
+    board: Option<BoardJson>,
+}
+
+#[derive(Deserialize)]
+struct HintsRequest {
+    #[serde(flatten)]
+    board: BoardRequest,
+    /// Also try Nishio and the forcing chains
+    #[serde(default)]
+    forcing: bool,
+}
+
+#[derive(Deserialize)]
+struct VerifyRequest {
+    #[serde(flatten)]
+    board: BoardRequest,
+    /// Maximum number of solutions to find before stopping
+    max: Option<usize>,
+}
+
+#[derive(Serialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+/// Parses an optional name the same way as the command line options.
+fn option_from_str<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: Display,
+{
+    Option::<String>::deserialize(deserializer)?
+        .map(|label| label.parse().map_err(serde::de::Error::custom))
+        .transpose()
+}
+
+/// Parses a list of names the same way as the command line options.
+fn vec_from_str<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: Display,
+{
+    Vec::<String>::deserialize(deserializer)?
+        .iter()
+        .map(|label| label.parse().map_err(serde::de::Error::custom))
+        .collect()
+}
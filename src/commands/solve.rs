@@ -1,17 +1,24 @@
+use std::cell::RefCell;
 use std::collections::HashMap;
-use std::io::BufRead;
+use std::io::{stdin, BufRead};
+use std::sync::mpsc::channel;
+use std::sync::Mutex;
+use std::thread::scope;
 use std::time::{Duration, Instant};
 
 use clap::Args;
 use itertools::Itertools;
 
-use crate::io::{
+use sudoku_rust::io::{
     format_for_wiki, format_number, format_runtime, print_all_and_single_candidates,
-    print_known_values, Cancelable, Parse, ParsePacked, Parser, SUDOKUWIKI_URL,
+    print_known_values, to_json, ActionJson, BoardJson, Cancelable, ErrorJson, FailureJson, Parse,
+    ParsePacked, Parser, ResolutionJson, ResolutionStatus, SUDOKUWIKI_URL,
 };
-use crate::layout::{Cell, Known};
-use crate::puzzle::{Action, Board, Changer, Difficulty, Effects, Options, Strategy};
-use crate::solve::{Reporter, Resolution, Solver, Timings};
+use sudoku_rust::layout::{Cell, Known};
+use sudoku_rust::puzzle::{Action, Board, Changer, Difficulty, Effects, Options, Strategy};
+use sudoku_rust::solve::{Rating, Reporter, Resolution, Solver, Timings};
+
+use super::{OutputFormat, TechniqueArgs, ThreadArgs};
 
 #[derive(Debug, Args)]
 pub struct SolveArgs {
@@ -19,6 +26,16 @@ pub struct SolveArgs {
     #[clap(short, long)]
     check: bool,
 
+    /// Print each resolution as JSON instead of boards and tables
+    #[clap(short, long, value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
+
+    #[clap(flatten)]
+    techniques: TechniqueArgs,
+
+    #[clap(flatten)]
+    threads: ThreadArgs,
+
     /// Clues for one or more puzzles to solve with detailed output
     puzzles: Option<Vec<String>>,
 }
@@ -28,9 +45,44 @@ pub fn solve_puzzles(args: SolveArgs) {
     let cancelable = Cancelable::new();
     let changer = Changer::new(Options::errors());
     let parser = Parse::packed_with_player(changer);
-    let solver = Solver::new(args.check);
+    let solver = Solver::new(args.check).with_techniques(args.techniques.techniques());
     let mut timings = Timings::new();
 
+    if args.format.is_json() {
+        match args.puzzles {
+            Some(puzzles) => {
+                for puzzle in puzzles {
+                    if cancelable.is_canceled() {
+                        break;
+                    }
+                    println!(
+                        "{}",
+                        to_json(&parse_and_solve_json(
+                            &parser,
+                            &solver,
+                            &mut timings,
+                            &puzzle
+                        ))
+                    );
+                }
+            }
+            None => {
+                solve_stdin(
+                    args.threads.worker_count(),
+                    &mut timings,
+                    |puzzle, timings| {
+                        let json = parse_and_solve_json(&parser, &solver, timings, puzzle);
+                        (
+                            json.status == ResolutionStatus::Solved,
+                            vec![Line::Out(to_json(&json))],
+                        )
+                    },
+                );
+            }
+        }
+        return;
+    }
+
     match args.puzzles {
         Some(puzzles) => {
             let reporter = DetailedReporter::new();
@@ -44,29 +96,24 @@ pub fn solve_puzzles(args: SolveArgs) {
             }
         }
         None => {
-            let reporter = CSVReporter::new();
-            let mut parser_solver = ParserSolver::new(&parser, &solver, &reporter, &mut timings);
-            let stdin = std::io::stdin();
-
             let runtime = Instant::now();
-            let mut count = 0;
-            let mut solved = 0;
 
-            println!("                   µs NS HS NP NT NQ HP HT HQ PP PT BL XW SC YW ER SF XZ JF SK TS AR XY UR AU FW EU HU WZ BG");
-            for puzzle in stdin.lock().lines().map_while(Result::ok) {
-                if cancelable.is_canceled() {
-                    break;
-                }
-                if parser_solver.parse_and_solve(&puzzle) {
-                    solved += 1;
-                }
-                count += 1;
-            }
+            println!("             SE         µs NS HS NP NT NQ HP HT HQ PP PT BL XW SC YW ER SF XZ JF SK TS AR XY UR AU FW EU HU WZ WW RP SD VW UW BG FX SX FS SS FJ SJ FF MF MC MD XC AI AZ AW DB PO NI CF UF DF JE");
+            let (count, solved) = solve_stdin(
+                args.threads.worker_count(),
+                &mut timings,
+                |puzzle, timings| {
+                    let reporter = CSVReporter::new();
+                    let solved = ParserSolver::new(&parser, &solver, &reporter, timings)
+                        .parse_and_solve(puzzle);
+                    (solved, reporter.lines.take())
+                },
+            );
 
             println!(
                 "\nsolved {} of {} puzzles in {} µs\n",
-                format_number(solved),
-                format_number(count),
+                format_number(solved as u128),
+                format_number(count as u128),
                 format_runtime(runtime.elapsed())
             );
         }
@@ -77,6 +124,113 @@ pub fn solve_puzzles(args: SolveArgs) {
     timings.print_totals();
 }
 
+/// A line of output for one puzzle, printed once every earlier puzzle has been printed.
+enum Line {
+    Out(String),
+    Err(String),
+}
+
+/// Solves each puzzle from STDIN on a pool of workers, printing their output in the order read
+/// and adding each worker's timings to `timings`. Returns the number of puzzles and solved puzzles.
+fn solve_stdin(
+    workers: usize,
+    timings: &mut Timings,
+    solve: impl Fn(&str, &mut Timings) -> (bool, Vec<Line>) + Sync,
+) -> (usize, usize) {
+    let cancelable = Cancelable::new();
+    let (puzzle_tx, puzzle_rx) = channel::<(usize, String)>();
+    let (result_tx, result_rx) = channel::<(usize, (bool, Vec<Line>))>();
+    let puzzle_rx = Mutex::new(puzzle_rx);
+
+    scope(|scope| {
+        let workers = (0..workers)
+            .map(|_| {
+                let (puzzle_rx, result_tx, solve) = (&puzzle_rx, result_tx.clone(), &solve);
+                scope.spawn(move || {
+                    let cancelable = Cancelable::new();
+                    let mut timings = Timings::new();
+                    loop {
+                        let Ok((index, puzzle)) = puzzle_rx.lock().unwrap().recv() else {
+                            break;
+                        };
+                        if cancelable.is_canceled() {
+                            break;
+                        }
+                        if result_tx
+                            .send((index, solve(&puzzle, &mut timings)))
+                            .is_err()
+                        {
+                            break;
+                        }
+                    }
+                    timings
+                })
+            })
+            .collect_vec();
+        drop(result_tx);
+
+        // Results arrive as workers finish, so hold each one until its turn
+        let printer = scope.spawn(move || {
+            let mut pending = HashMap::new();
+            let (mut count, mut solved) = (0, 0);
+            for (index, result) in result_rx {
+                pending.insert(index, result);
+                while let Some((was_solved, lines)) = pending.remove(&count) {
+                    for line in lines {
+                        match line {
+                            Line::Out(line) => println!("{}", line),
+                            Line::Err(line) => eprintln!("{}", line),
+                        }
+                    }
+                    count += 1;
+                    solved += was_solved as usize;
+                }
+            }
+            (count, solved)
+        });
+
+        for (index, puzzle) in stdin().lock().lines().map_while(Result::ok).enumerate() {
+            if cancelable.is_canceled() {
+                break;
+            }
+            puzzle_tx.send((index, puzzle)).unwrap();
+        }
+        drop(puzzle_tx);
+
+        for worker in workers {
+            timings.merge(worker.join().unwrap());
+        }
+        printer.join().unwrap()
+    })
+}
+
+/// Solves the puzzle and describes the result,
+/// reporting invalid givens as the given that caused the errors.
+fn parse_and_solve_json(
+    parser: &ParsePacked,
+    solver: &Solver,
+    timings: &mut Timings,
+    givens: &str,
+) -> ResolutionJson {
+    let (start, effects, failure) = parser.parse(givens);
+
+    if let Some((cell, known)) = failure {
+        return ResolutionJson {
+            status: ResolutionStatus::Invalid,
+            difficulty: Difficulty::Trivial,
+            rating: None,
+            board: BoardJson::new(&start),
+            actions: vec![],
+            failure: Some(FailureJson {
+                action: ActionJson::new(&Action::new_set(Strategy::Given, cell, known)),
+                errors: effects.errors_iter().map(ErrorJson::new).collect(),
+            }),
+        };
+    }
+
+    ResolutionJson::new(&solver.solve(&start, &effects, timings))
+}
+
 struct ParserSolver<'a> {
     parser: &'a ParsePacked,
     solver: &'a Solver,
@@ -133,6 +287,7 @@ impl ParserSolver<'_> {
                     &start,
                     &solution,
                     difficulty,
+                    Rating::new(&actions),
                     runtime.elapsed(),
                     &actions.action_counts(),
                 );
@@ -225,12 +380,15 @@ impl Reporter for DetailedReporter {
         _start: &Board,
         solution: &Board,
         difficulty: Difficulty,
+        rating: Rating,
         runtime: Duration,
         counts: &HashMap<Strategy, i32>,
     ) {
         println!(
-            "solved {:?} in {} µs - {}\n",
+            "solved {:?} rated {} with {} hard steps in {} µs - {}\n",
             difficulty,
+            rating,
+            rating.hard_steps(),
             format_runtime(runtime),
             solution.packed_string()
         );
@@ -241,16 +399,21 @@ impl Reporter for DetailedReporter {
     }
 }
 
-struct CSVReporter {}
+/// Collects a line for each puzzle to be printed in order with the others.
+struct CSVReporter {
+    lines: RefCell<Vec<Line>>,
+}
 
 impl CSVReporter {
     fn new() -> CSVReporter {
-        CSVReporter {}
+        CSVReporter {
+            lines: RefCell::new(Vec::new()),
+        }
     }
 
     fn format_counts(&self, counts: &HashMap<Strategy, i32>) -> String {
         format!(
-            "{:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2}",
+            "{:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2} {:>2}",
             // counts.get(&Strategy::Peer).unwrap_or(0),
             counts.get(&Strategy::NakedSingle).unwrap_or(&0),
             counts.get(&Strategy::HiddenSingle).unwrap_or(&0),
@@ -283,7 +446,33 @@ impl CSVReporter {
             counts.get(&Strategy::ExtendedUniqueRectangle).unwrap_or(&0),
             counts.get(&Strategy::HiddenUniqueRectangle).unwrap_or(&0),
             counts.get(&Strategy::WXYZWing).unwrap_or(&0),
+            counts.get(&Strategy::WWing).unwrap_or(&0),
+            counts.get(&Strategy::RemotePairs).unwrap_or(&0),
+            counts.get(&Strategy::SueDeCoq).unwrap_or(&0),
+            counts.get(&Strategy::VWXYZWing).unwrap_or(&0),
+            counts.get(&Strategy::UVWXYZWing).unwrap_or(&0),
             counts.get(&Strategy::Bug).unwrap_or(&0),
+            counts.get(&Strategy::FinnedXWing).unwrap_or(&0),
+            counts.get(&Strategy::SashimiXWing).unwrap_or(&0),
+            counts.get(&Strategy::FinnedSwordfish).unwrap_or(&0),
+            counts.get(&Strategy::SashimiSwordfish).unwrap_or(&0),
+            counts.get(&Strategy::FinnedJellyfish).unwrap_or(&0),
+            counts.get(&Strategy::SashimiJellyfish).unwrap_or(&0),
+            counts.get(&Strategy::FrankenFish).unwrap_or(&0),
+            counts.get(&Strategy::MutantFish).unwrap_or(&0),
+            counts.get(&Strategy::MultiColoring).unwrap_or(&0),
+            counts.get(&Strategy::Medusa3D).unwrap_or(&0),
+            counts.get(&Strategy::XCycle).unwrap_or(&0),
+            counts.get(&Strategy::AlternatingInferenceChain).unwrap_or(&0),
+            counts.get(&Strategy::AlsXZ).unwrap_or(&0),
+            counts.get(&Strategy::AlsXYWing).unwrap_or(&0),
+            counts.get(&Strategy::DeathBlossom).unwrap_or(&0),
+            counts.get(&Strategy::PatternOverlay).unwrap_or(&0),
+            counts.get(&Strategy::Nishio).unwrap_or(&0),
+            counts.get(&Strategy::CellForcingChain).unwrap_or(&0),
+            counts.get(&Strategy::UnitForcingChain).unwrap_or(&0),
+            counts.get(&Strategy::DigitForcingChain).unwrap_or(&0),
+            counts.get(&Strategy::JuniorExocet).unwrap_or(&0),
         )
     }
 }
@@ -298,7 +487,10 @@ impl Reporter for CSVReporter {
         known: Known,
         _runtime: Duration,
     ) {
-        eprintln!("invalid: cannot set {} to {} for {}", cell, known, givens);
+        self.lines.borrow_mut().push(Line::Err(format!(
+            "invalid: cannot set {} to {} for {}",
+            cell, known, givens
+        )));
     }
 
     fn failed(
@@ -311,12 +503,12 @@ impl Reporter for CSVReporter {
         runtime: Duration,
         counts: &HashMap<Strategy, i32>,
     ) {
-        println!(
-            "Invalid    {:>10} {} {}",
+        self.lines.borrow_mut().push(Line::Out(format!(
+            "Invalid         {:>10} {} {}",
             format_runtime(runtime),
             self.format_counts(counts),
             start.packed_string()
-        );
+        )));
     }
 
     fn unsolved(
@@ -327,13 +519,13 @@ impl Reporter for CSVReporter {
         runtime: Duration,
         counts: &HashMap<Strategy, i32>,
     ) {
-        println!(
-            "Unsolved   {:>10} {} {}",
+        self.lines.borrow_mut().push(Line::Out(format!(
+            "Unsolved        {:>10} {} {}",
             format_runtime(runtime),
             self.format_counts(counts),
             // givens,
             start.packed_string()
-        );
+        )));
     }
 
     fn solved(
@@ -342,15 +534,17 @@ impl Reporter for CSVReporter {
         start: &Board,
         _solution: &Board,
         difficulty: Difficulty,
+        rating: Rating,
         runtime: Duration,
         counts: &HashMap<Strategy, i32>,
     ) {
-        println!(
-            "{:<10} {:>10} {} {}",
+        self.lines.borrow_mut().push(Line::Out(format!(
+            "{:<10} {:>4} {:>10} {} {}",
             format!("{:?}", difficulty),
+            rating.to_string(),
             format_runtime(runtime),
             self.format_counts(counts),
             start.packed_string()
-        );
+        )));
     }
 }
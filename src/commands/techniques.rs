@@ -0,0 +1,78 @@
+use std::fs::read_to_string;
+use std::process::exit;
+
+use clap::Args;
+
+use sudoku_rust::solve::{
+    exclude_techniques, select_techniques, Profiles, Technique, NON_PEER_TECHNIQUES,
+};
+
+/// Selects the techniques used by the solver.
+#[derive(Debug, Args)]
+pub struct TechniqueArgs {
+    /// Comma-separated techniques to use in order, difficulties, "uniqueness" or "all"
+    #[clap(long, conflicts_with = "profile")]
+    techniques: Option<String>,
+
+    /// Comma-separated techniques to skip, such as "uniqueness" for non-unique puzzles
+    #[clap(long)]
+    exclude: Option<String>,
+
+    /// Use the techniques from a named profile
+    #[clap(long)]
+    profile: Option<String>,
+
+    /// File with additional profiles, one "name = techniques" per line
+    #[clap(long, requires = "profile")]
+    profiles: Option<String>,
+}
+
+impl TechniqueArgs {
+    /// Returns the selected techniques or exits with an error.
+    pub fn techniques(&self) -> Vec<Technique> {
+        match self.select() {
+            Ok(techniques) if !techniques.is_empty() => techniques,
+            Ok(_) => {
+                eprintln!("==> No techniques selected");
+                exit(1);
+            }
+            Err(error) => {
+                eprintln!("==> {}", error);
+                exit(1);
+            }
+        }
+    }
+
+    fn select(&self) -> Result<Vec<Technique>, String> {
+        let mut techniques = if let Some(name) = &self.profile {
+            let profiles = match &self.profiles {
+                Some(path) => {
+                    let text = read_to_string(path)
+                        .map_err(|error| format!("Cannot read {}: {}", path, error))?;
+                    Profiles::parse(&text).map_err(|error| format!("{}: {}", path, error))?
+                }
+                None => Profiles::new(),
+            };
+            match profiles.get(name) {
+                Some(techniques) => techniques.to_vec(),
+                None => {
+                    return Err(format!(
+                        "Unknown profile \"{}\"; expected one of {}",
+                        name,
+                        profiles.names().collect::<Vec<_>>().join(", ")
+                    ))
+                }
+            }
+        } else if let Some(list) = &self.techniques {
+            select_techniques(list)?
+        } else {
+            NON_PEER_TECHNIQUES.to_vec()
+        };
+
+        if let Some(list) = &self.exclude {
+            exclude_techniques(&mut techniques, list)?;
+        }
+
+        Ok(techniques)
+    }
+}
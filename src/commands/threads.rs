@@ -0,0 +1,36 @@
+use std::thread::available_parallelism;
+
+use clap::Args;
+
+/// Selects the number of worker threads.
+#[derive(Debug, Args)]
+pub struct ThreadArgs {
+    /// Worker thread count; negative values are relative to core count
+    #[clap(short, long, allow_negative_numbers = true)]
+    threads: Option<isize>,
+}
+
+impl ThreadArgs {
+    /// Returns the requested number of workers, leaving one core free by default.
+    pub fn worker_count(&self) -> usize {
+        determine_worker_count(self.threads)
+    }
+}
+
+fn determine_worker_count(requested: Option<isize>) -> usize {
+    let num_cores = available_parallelism().unwrap().get() as isize;
+    let count = if let Some(count) = requested {
+        if count < 0 {
+            num_cores + count
+        } else {
+            count
+        }
+    } else {
+        num_cores - 1
+    };
+    if count < 1 {
+        1
+    } else {
+        count as usize
+    }
+}
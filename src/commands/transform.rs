@@ -0,0 +1,142 @@
+use std::io::BufRead;
+
+use clap::{Args, ValueEnum};
+use rand::SeedableRng;
+use rand_chacha::ChaCha8Rng;
+
+use sudoku_rust::io::{format_number, to_json, Cancelable, Parse, Parser, TransformJson};
+use sudoku_rust::puzzle::{Options, Symmetry};
+
+use super::OutputFormat;
+
+#[derive(Debug, Args)]
+pub struct TransformArgs {
+    /// Seed the random symmetries to transform the same way every time
+    #[clap(long, conflicts_with_all = ["apply", "transpose", "rotate", "reflect"])]
+    seed: Option<u64>,
+
+    /// Apply this symmetry, written as [T/]rows/columns/digits, instead of a random one
+    #[clap(short, long)]
+    apply: Option<Symmetry>,
+
+    /// Swap the rows and columns
+    #[clap(short, long)]
+    transpose: bool,
+
+    /// Rotate clockwise by 90, 180 or 270 degrees
+    #[clap(short, long, value_parser = parse_degrees)]
+    rotate: Option<usize>,
+
+    /// Mirror the grid across an axis
+    #[clap(long, value_enum)]
+    reflect: Option<Axis>,
+
+    /// Print each puzzle with its symmetries as JSON
+    #[clap(short, long, value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
+
+    /// Clues for the puzzles to transform instead of reading them from STDIN
+    puzzles: Option<Vec<String>>,
+}
+
+/// The axis to mirror the grid across.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, ValueEnum)]
+enum Axis {
+    /// Swap the left and right sides
+    Horizontal,
+    /// Swap the top and bottom
+    Vertical,
+}
+
+/// Moves the cells and relabels the digits of each puzzle,
+/// printing it with the symmetry that maps its solution back.
+pub fn transform_puzzles(args: TransformArgs) {
+    let cancelable = Cancelable::new();
+    let parser = Parse::packed_with_options(Options::errors());
+    let json = args.format.is_json();
+
+    // the listed moves are applied in the order of the options
+    let mut fixed = args.apply;
+    if args.transpose {
+        fixed = Some(fixed.unwrap_or_default().then(&Symmetry::transpose()));
+    }
+    if let Some(degrees) = args.rotate {
+        fixed = Some(
+            fixed
+                .unwrap_or_default()
+                .then(&Symmetry::rotate(degrees / 90)),
+        );
+    }
+    if let Some(axis) = args.reflect {
+        let reflect = match axis {
+            Axis::Horizontal => Symmetry::reflect_horizontally(),
+            Axis::Vertical => Symmetry::reflect_vertically(),
+        };
+        fixed = Some(fixed.unwrap_or_default().then(&reflect));
+    }
+
+    let seed = match fixed {
+        Some(_) => None,
+        None => Some(args.seed.unwrap_or_else(rand::random)),
+    };
+    let mut rng = ChaCha8Rng::seed_from_u64(seed.unwrap_or_default());
+
+    let mut transform = |puzzle: &str| {
+        let (board, _, failure) = parser.parse(puzzle);
+        if failure.is_some() {
+            eprintln!("{} - invalid", puzzle);
+            return false;
+        }
+
+        let symmetry = fixed.unwrap_or_else(|| Symmetry::random(&mut rng));
+        if json {
+            println!("{}", to_json(&TransformJson::new(&board, &symmetry, seed)));
+        } else {
+            println!(
+                "{} {}",
+                symmetry.apply(&board).packed_string(),
+                symmetry.inverse()
+            );
+        }
+        true
+    };
+
+    let mut count: usize = 0;
+    match args.puzzles {
+        Some(puzzles) => {
+            for puzzle in puzzles {
+                count += transform(&puzzle) as usize;
+            }
+        }
+        None => {
+            for puzzle in std::io::stdin().lock().lines().map_while(Result::ok) {
+                if cancelable.is_canceled() {
+                    break;
+                }
+                if !puzzle.trim().is_empty() {
+                    count += transform(&puzzle) as usize;
+                }
+            }
+        }
+    }
+
+    if json {
+        return;
+    }
+    let puzzles = match count {
+        1 => "1 puzzle".to_string(),
+        _ => format!("{} puzzles", format_number(count as u128)),
+    };
+    match seed {
+        Some(seed) => eprintln!("\n==> Transformed {} from seed {}", puzzles, seed),
+        None => eprintln!("\n==> Transformed {}", puzzles),
+    }
+    eprintln!("    Pass the inverse printed after a puzzle to --apply to map its solution back");
+}
+
+fn parse_degrees(degrees: &str) -> Result<usize, String> {
+    match degrees.parse::<usize>() {
+        Ok(degrees @ (90 | 180 | 270)) => Ok(degrees),
+        _ => Err("must be 90, 180 or 270".to_string()),
+    }
+}
@@ -12,7 +12,7 @@
 //! ignore all other characters, and formatting one has an option to place
 //! spaces between each row of 9 cells.
 //!
-//! ```
+//! ```text
 //! ...3.5.7. .48.....1 ...71.... .5...6... .......9. ....531.4 .9..8.... ..2....47 .8.....2.
 //! ```
 //!
@@ -21,7 +21,7 @@
 //! This format was created Andrew Stuart of [SudokuWiki](https://www.sudokuwiki.org/)
 //! to provide the full puzzle state: given clues, solved cells, and cell candidates.
 //!
-//! ```
+//! ```text
 //! 811003080g44g02044090g21g002441080444104g0108020030g080g201004098040g10202g04021100g05088104800840g0030h1120g0090402211080400h10400h8005082003g12102800h41g0090410
 //! ```
 //!
@@ -31,7 +31,7 @@
 //! Each lone digit is either a given or solved cell while each group of digits
 //! represent a cell's remaining candidates.
 //!
-//! ```
+//! ```text
 //! +--------------------+-----------------------+-------------------+
 //! | 1     257    457   | 9     6        458    | 478    3     2478 |
 //! | 34679 3679   4679  | 2     13478    148    | 146789 4678  5    |
@@ -47,16 +47,38 @@
 //! +--------------------+-----------------------+-------------------+
 //! ```
 //!
+//! **JSON**
+//!
+//! Tools that need more than a string use the versioned JSON documents
+//! such as [`BoardJson`] and [`ResolutionJson`]. Pass them to [`to_json`]
+//! and [`from_json`] to tag and check the [`FORMAT_VERSION`]. Boards and actions
+//! may be converted back after parsing.
+//!
+//! ```text
+//! {"version":1,"givens":"..2...376...","knowns":"..2...376...","candidates":["458","59","",...]}
+//! ```
+//!
 //! [`Cancelable`] is used to detect when the user presses `Ctrl-C`
 //! so a long-running process can be stopped without terminating the program.
 //!
+//! Use [`explain`] to describe an action taken by the solver in plain English.
+//!
 //! Finally, use [`show_progress`] to display a progress bar while building
 //! or solving a puzzle and [`format_runtime`] and [`format_number`] for logging.
 
 pub use cancelable::{create_signal, Cancelable};
-pub use format::{format_for_fancy_console, format_for_wiki, format_grid, format_packed, Format};
+pub use explain::explain;
+pub use format::{
+    format_for_console, format_for_fancy_console, format_for_url, format_for_wiki, format_grid,
+    format_packed, Format, FormatGrid, FormatPacked, FormatWiki,
+};
+pub use json::{
+    from_json, to_json, ActionJson, BoardJson, BruteForceJson, BruteForceStatus, EffectsJson,
+    ErrorJson, FailureJson, PuzzleJson, RatingJson, ResolutionJson, ResolutionStatus,
+    SolutionCountJson, SolutionCountStatus, TransformJson, FORMAT_VERSION,
+};
 pub use numbers::{format_number, format_runtime};
-pub use parse::{Parse, ParsePacked, Parser};
+pub use parse::{Parse, ParseGrid, ParsePacked, ParseWiki, Parser};
 pub use print::{
     print_all_and_single_candidates, print_all_and_single_candidates_with_highlight,
     print_candidate, print_givens, print_known_values,
@@ -64,7 +86,9 @@ pub use print::{
 pub use progress::show_progress;
 
 mod cancelable;
+mod explain;
 mod format;
+mod json;
 mod numbers;
 mod parse;
 mod print;
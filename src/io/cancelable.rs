@@ -1,5 +1,6 @@
 use std::sync::atomic::{AtomicBool, Ordering};
 
+#[derive(Default)]
 pub struct Cancelable {}
 
 impl Cancelable {
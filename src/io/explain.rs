@@ -0,0 +1,512 @@
+use itertools::Itertools;
+
+use crate::layout::{Cell, CellSet, House, HouseSet, Known, KnownSet, Shape};
+use crate::puzzle::{Action, Strategy, Verdict};
+
+/// Describes an action in a single English sentence
+/// using its clues to name the pattern that justifies it.
+///
+/// ```text
+/// X-Wing on 4 in rows B and F, columns 2 and 7, removes 4 from B5, F5
+/// ```
+pub fn explain(action: &Action) -> String {
+    let strategy = action.strategy();
+    let pattern = pattern_cells(action);
+    let digits = pattern_knowns(action);
+    let effect = describe_effect(action);
+
+    match strategy {
+        Strategy::Given => format!("{} {}", strategy, effect),
+        Strategy::Solve | Strategy::Erase => format!("Player {}", effect),
+        Strategy::Peer => format!("{} {} as a peer of a solved cell", strategy, effect),
+        Strategy::NakedSingle => format!("{} {} as its only candidate", strategy, effect),
+        Strategy::HiddenSingle => {
+            let (cell, known) = action.collect_sets().next().unwrap();
+            match common_house(related_cells(action) + cell) {
+                Some(house) => format!(
+                    "{} {} as the only place for {} in {}",
+                    strategy, effect, known, house
+                ),
+                None => format!("{} {}", strategy, effect),
+            }
+        }
+        Strategy::NakedPair
+        | Strategy::NakedTriple
+        | Strategy::NakedQuad
+        | Strategy::HiddenPair
+        | Strategy::HiddenTriple
+        | Strategy::HiddenQuad => {
+            let house = common_house(pattern | related_cells(action) | erased_cells(action));
+            format!(
+                "{} on {} in cells {}{}, {}",
+                strategy,
+                digits,
+                join_cells(pattern),
+                house.map_or(String::new(), |house| format!(" of {}", house)),
+                effect
+            )
+        }
+        Strategy::IntersectionRemoval
+        | Strategy::PointingPair
+        | Strategy::PointingTriple
+        | Strategy::BoxLineReduction => {
+            let houses = [pattern.common_block(), pattern.common_row_or_column()]
+                .into_iter()
+                .flatten()
+                .map(|house| house.label().to_string());
+            format!("{} on {} in {}, {}", strategy, digits, join(houses), effect)
+        }
+        Strategy::XWing | Strategy::Swordfish | Strategy::Jellyfish => {
+            let erased = erased_cells(action);
+            let (mains, crosses) = if erased.rows().has_any(pattern.rows()) {
+                (pattern.columns(), pattern.rows())
+            } else {
+                (pattern.rows(), pattern.columns())
+            };
+            format!(
+                "{} on {} in {}, {}, {}",
+                strategy,
+                digits,
+                describe_houses(mains),
+                describe_houses(crosses),
+                effect
+            )
+        }
+        Strategy::YWing
+        | Strategy::XYZWing
+        | Strategy::WXYZWing
+        | Strategy::VWXYZWing
+        | Strategy::UVWXYZWing => match find_pivot(action, pattern) {
+            Some(pivot) => format!(
+                "{} on {} with pivot {} and wings {}, {}",
+                strategy,
+                digits,
+                pivot,
+                join_cells(pattern - pivot),
+                effect
+            ),
+            None => format!(
+                "{} on {} in cells {}, {}",
+                strategy,
+                digits,
+                join_cells(pattern),
+                effect
+            ),
+        },
+        Strategy::WWing => format!(
+            "{} on {} with wings {} joined by a strong link on {} in {}, {}",
+            strategy,
+            describe_knowns(verdict_knowns(action, Verdict::Secondary)),
+            join_cells(verdict_cells(action, Verdict::Secondary)),
+            describe_knowns(verdict_knowns(action, Verdict::Primary)),
+            join_cells(verdict_cells(action, Verdict::Primary)),
+            effect
+        ),
+        Strategy::XYChain
+        | Strategy::RemotePairs
+        | Strategy::XCycle
+        | Strategy::AlternatingInferenceChain => format!(
+            "{} on {} alternating between {} and {}, {}",
+            strategy,
+            digits,
+            describe_candidates(action, Verdict::Secondary),
+            describe_candidates(action, Verdict::Tertiary),
+            effect
+        ),
+        Strategy::SinglesChain | Strategy::MultiColoring | Strategy::Medusa3D => {
+            let second = verdict_cells(action, Verdict::Primary);
+            format!(
+                "{} on {} coloring {} against {}{}, {}",
+                strategy,
+                digits,
+                describe_candidates(action, Verdict::Secondary),
+                describe_candidates(action, Verdict::Tertiary),
+                if second.is_empty() {
+                    String::new()
+                } else {
+                    format!(
+                        " with a second chain of {} against {}",
+                        describe_candidates(action, Verdict::Primary),
+                        describe_candidates(action, Verdict::Related)
+                    )
+                },
+                effect
+            )
+        }
+        Strategy::Nishio => format!(
+            "{} assuming {} leads to a contradiction, {}",
+            strategy,
+            describe_candidates(action, Verdict::Primary),
+            effect
+        ),
+        Strategy::CellForcingChain | Strategy::UnitForcingChain | Strategy::DigitForcingChain => {
+            format!(
+                "{} assuming each of {} in turn, {}",
+                strategy,
+                describe_candidates(action, Verdict::Primary),
+                effect
+            )
+        }
+        Strategy::AlsXZ => format!(
+            "{} joining {} and {} by restricted common {}, {}",
+            strategy,
+            describe_cells(verdict_cells(action, Verdict::Primary)),
+            describe_cells(verdict_cells(action, Verdict::Secondary)),
+            describe_knowns(verdict_knowns(action, Verdict::Tertiary)),
+            effect
+        ),
+        Strategy::AlsXYWing => format!(
+            "{} joining pivot {} to {} by restricted commons {}, {}",
+            strategy,
+            describe_cells(verdict_cells(action, Verdict::Secondary)),
+            describe_cells(verdict_cells(action, Verdict::Primary)),
+            describe_knowns(verdict_knowns(action, Verdict::Tertiary)),
+            effect
+        ),
+        Strategy::DeathBlossom => {
+            let petals = verdict_cells(action, Verdict::Primary);
+            let stem = verdict_cells(action, Verdict::Secondary) - petals;
+            format!(
+                "{} with stem {} and petals {}, {}",
+                strategy,
+                describe_cells(stem),
+                describe_cells(petals),
+                effect
+            )
+        }
+        Strategy::JuniorExocet => format!(
+            "{} on {} with base {} and targets {}, {}",
+            strategy,
+            describe_knowns(verdict_knowns(action, Verdict::Primary)),
+            join_cells(verdict_cells(action, Verdict::Primary)),
+            join_cells(verdict_cells(action, Verdict::Secondary)),
+            effect
+        ),
+        _ => format!(
+            "{} on {} using {}, {}",
+            strategy,
+            digits,
+            join_cells(pattern),
+            effect
+        ),
+    }
+}
+
+/// Returns the cells that form the pattern, leaving out those only related to it.
+fn pattern_cells(action: &Action) -> CellSet {
+    action
+        .collect_clues()
+        .filter(|(_, _, verdict)| *verdict != Verdict::Related)
+        .fold(CellSet::empty(), |cells, (cell, _, _)| cells + cell)
+}
+
+fn related_cells(action: &Action) -> CellSet {
+    action
+        .collect_clues()
+        .filter(|(_, _, verdict)| *verdict == Verdict::Related)
+        .fold(CellSet::empty(), |cells, (cell, _, _)| cells + cell)
+}
+
+fn verdict_cells(action: &Action, verdict: Verdict) -> CellSet {
+    action
+        .collect_clues()
+        .filter(|(_, _, clued)| *clued == verdict)
+        .fold(CellSet::empty(), |cells, (cell, _, _)| cells + cell)
+}
+
+fn verdict_knowns(action: &Action, verdict: Verdict) -> KnownSet {
+    action
+        .collect_clues()
+        .filter(|(_, _, clued)| *clued == verdict)
+        .fold(KnownSet::empty(), |knowns, (_, known, _)| knowns + known)
+}
+
+/// Returns the pattern cell that sees every other one and holds the most clued digits.
+fn find_pivot(action: &Action, pattern: CellSet) -> Option<Cell> {
+    pattern
+        .iter()
+        .filter(|cell| (pattern - *cell).is_subset_of(cell.peers()))
+        .max_by_key(|cell| {
+            action
+                .collect_clues()
+                .filter(|(clued, _, verdict)| clued == cell && *verdict != Verdict::Related)
+                .count()
+        })
+}
+
+fn erased_cells(action: &Action) -> CellSet {
+    action
+        .collect_erases()
+        .fold(CellSet::empty(), |cells, (cell, _)| cells + cell)
+}
+
+/// Returns the digits in the pattern, or those affected when it has no clues.
+fn pattern_knowns(action: &Action) -> String {
+    let mut knowns = action
+        .collect_clues()
+        .filter(|(_, _, verdict)| *verdict != Verdict::Related)
+        .fold(KnownSet::empty(), |knowns, (_, known, _)| knowns + known);
+    if knowns.is_empty() {
+        knowns = action
+            .collect_erases()
+            .fold(KnownSet::empty(), |knowns, (_, erased)| knowns | erased);
+        knowns = action
+            .collect_sets()
+            .fold(knowns, |knowns, (_, known)| knowns + known);
+    }
+
+    describe_knowns(knowns)
+}
+
+/// Describes the candidates removed and cells solved by an action.
+fn describe_effect(action: &Action) -> String {
+    let mut parts = Vec::new();
+
+    let erases = action.collect_erases().fold(
+        Vec::<(KnownSet, CellSet)>::new(),
+        |mut groups, (cell, knowns)| {
+            match groups.iter_mut().find(|(group, _)| *group == knowns) {
+                Some((_, cells)) => *cells += cell,
+                None => groups.push((knowns, CellSet::empty() + cell)),
+            }
+            groups
+        },
+    );
+    if !erases.is_empty() {
+        parts.push(format!(
+            "removes {}",
+            join(erases.iter().map(|(knowns, cells)| format!(
+                "{} from {}",
+                describe_knowns(*knowns),
+                describe_cells(*cells)
+            )))
+        ));
+    }
+
+    let sets = action.collect_sets().collect_vec();
+    if !sets.is_empty() {
+        parts.push(format!(
+            "sets {}",
+            join(
+                sets.iter()
+                    .map(|(cell, known)| format!("{} to {}", cell, known))
+            )
+        ));
+    }
+
+    if parts.is_empty() {
+        "changes nothing".to_string()
+    } else {
+        parts.join(" and ")
+    }
+}
+
+/// Describes the candidates clued with one verdict as "1 in B1, G3 and 9 in G2".
+fn describe_candidates(action: &Action, verdict: Verdict) -> String {
+    join(Known::iter().filter_map(|known| {
+        let cells = action
+            .collect_clues()
+            .filter(|(_, clued, clued_verdict)| *clued == known && *clued_verdict == verdict)
+            .fold(CellSet::empty(), |cells, (cell, _, _)| cells + cell);
+        (!cells.is_empty()).then(|| format!("{} in {}", known, describe_cells(cells)))
+    }))
+}
+
+fn join_cells(cells: CellSet) -> String {
+    join(cells.iter().map(|cell| cell.label()))
+}
+
+fn describe_cells(cells: CellSet) -> String {
+    cells.iter().map(|cell| cell.label()).join(", ")
+}
+
+fn describe_knowns(knowns: KnownSet) -> String {
+    join(knowns.iter().map(|known| known.label().to_string()))
+}
+
+/// Describes houses of one shape as "rows B and F" or "columns 2, 5 and 7".
+fn describe_houses(houses: HouseSet) -> String {
+    let shape = houses.shape();
+    let labels = houses.iter().map(|house| match shape {
+        Shape::Row => house.console_label().to_string(),
+        _ => house.coord().label().to_string(),
+    });
+    let name = match (shape, houses.len()) {
+        (Shape::Row, 1) => "row",
+        (Shape::Row, _) => "rows",
+        (Shape::Column, 1) => "column",
+        (Shape::Column, _) => "columns",
+        (Shape::Block, 1) => "box",
+        (Shape::Block, _) => "boxes",
+    };
+
+    format!("{} {}", name, join(labels))
+}
+
+/// Returns the row, column or box containing every cell, in that order of preference.
+fn common_house(cells: CellSet) -> Option<House> {
+    cells
+        .common_row_or_column()
+        .or_else(|| cells.common_block())
+}
+
+/// Joins the items with commas and "and" before the last one.
+fn join<T: ToString>(items: impl Iterator<Item = T>) -> String {
+    let items = items.map(|item| item.to_string()).collect_vec();
+    match items.len() {
+        0 => String::new(),
+        1 => items[0].clone(),
+        n => format!("{} and {}", items[..n - 1].join(", "), items[n - 1]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::io::{Parse, Parser};
+    use crate::layout::cells::cell::cell;
+    use crate::layout::cells::cell_set::cells;
+    use crate::layout::values::known::known;
+    use crate::layout::values::known_set::knowns;
+    use crate::puzzle::{Board, Effects, Options};
+    use crate::solve::algorithms::{find_hidden_singles, find_naked_pairs, find_x_wings};
+
+    use super::*;
+
+    #[test]
+    fn explains_x_wing() {
+        let board = Parse::packed_with_options(Options::errors()).parse_simple(
+            "
+                1.....569
+                492.561.8
+                .561.924.
+                ..964.8.1
+                .64.1....
+                218.356.4
+                .4.5...16
+                9.5.614.2
+                621.....5
+            ",
+        );
+
+        let found = find_x_wings(&board, true).unwrap();
+
+        assert_eq!(
+            "X-Wing on 7 in rows B and F, columns 4 and 8, removes 7 from A4, D8, E4, E8, H4, H8, J4, J8",
+            explain(&found.actions()[0])
+        );
+    }
+
+    #[test]
+    fn explains_singles() {
+        let mut naked = Action::new_set(Strategy::NakedSingle, cell!("A1"), known!("5"));
+        naked.clue_cell_for_knowns(
+            Verdict::Related,
+            cell!("A1"),
+            KnownSet::full() - known!("5"),
+        );
+        assert_eq!(
+            "Naked Single sets A1 to 5 as its only candidate",
+            explain(&naked)
+        );
+
+        let board = Parse::packed().parse_simple(
+            "..2...376 .1..3.5.. .......9. 9..85...1 ...3.4... 2...97..3 .8....... ..3.4..6. 147...2..",
+        );
+        let found = find_hidden_singles(&board, true).unwrap();
+        let explanation = explain(&found.actions()[0]);
+        assert!(explanation.starts_with("Hidden Single sets "));
+        assert!(explanation.contains(" as the only place for "));
+    }
+
+    #[test]
+    fn explains_tuples() {
+        let mut board = Board::new();
+        let mut effects = Effects::new();
+        board.remove_candidates_from_cells(cells!("A1 A2"), knowns!("1 2 3 4 5 6 7"), &mut effects);
+
+        let found = find_naked_pairs(&board, true).unwrap();
+        let explanation = explain(&found.actions()[0]);
+
+        assert!(explanation.starts_with("Naked Pair on 8 and 9 in cells A1 and A2 of "));
+        assert!(explanation.contains(", removes 8 and 9 from "));
+    }
+
+    #[test]
+    fn explains_wings() {
+        let mut action = Action::new(Strategy::XYZWing);
+        action.erase(cell!("F7"), known!("1"));
+        action.clue_cells_for_known(Verdict::Secondary, cells!("D9 F1 F9"), known!("1"));
+        action.clue_cells_for_known(Verdict::Primary, cells!("D9 F9"), known!("2"));
+        action.clue_cells_for_known(Verdict::Primary, cells!("F1 F9"), known!("4"));
+
+        assert_eq!(
+            "XYZ-Wing on 1, 2 and 4 with pivot F9 and wings D9 and F1, removes 1 from F7",
+            explain(&action)
+        );
+    }
+
+    #[test]
+    fn explains_chains() {
+        let mut action = Action::new(Strategy::XCycle);
+        action.erase(cell!("H4"), known!("9"));
+        action.clue_cells_for_known(Verdict::Secondary, cells!("F3 G1 H8"), known!("9"));
+        action.clue_cells_for_known(Verdict::Tertiary, cells!("D1 F4 G8"), known!("9"));
+
+        assert_eq!(
+            "X-Cycle on 9 alternating between 9 in F3, G1, H8 and 9 in D1, F4, G8, removes 9 from H4",
+            explain(&action)
+        );
+    }
+
+    #[test]
+    fn explains_coloring() {
+        let mut action = Action::new(Strategy::SinglesChain);
+        action.erase(cell!("C2"), known!("7"));
+        action.clue_cells_for_known(Verdict::Secondary, cells!("A2 E9"), known!("7"));
+        action.clue_cells_for_known(Verdict::Tertiary, cells!("A9 C8"), known!("7"));
+
+        assert_eq!(
+            "Singles Chain on 7 coloring 7 in A2, E9 against 7 in A9, C8, removes 7 from C2",
+            explain(&action)
+        );
+    }
+
+    #[test]
+    fn explains_almost_locked_sets() {
+        let mut action = Action::new(Strategy::AlsXZ);
+        action.erase(cell!("G5"), known!("3"));
+        action.clue_cells_for_knowns(Verdict::Primary, cells!("G4 G6"), knowns!("3 7"));
+        action.clue_cells_for_knowns(Verdict::Secondary, cells!("D8 E8"), knowns!("1 2 4 5"));
+        action.clue_cell_for_knowns(Verdict::Secondary, cell!("F8"), knowns!("1 4 5"));
+        action.clue_cell_for_knowns(Verdict::Secondary, cell!("G8"), knowns!("1 3 4 5"));
+        action.clue_cell_for_knowns(Verdict::Secondary, cell!("J8"), knowns!("2 3 5"));
+        action.clue_cells_for_known(Verdict::Tertiary, cells!("G4 G6 G8"), known!("6"));
+
+        assert_eq!(
+            "ALS-XZ joining G4, G6 and D8, E8, F8, G8, J8 by restricted common 6, removes 3 from G5",
+            explain(&action)
+        );
+    }
+
+    #[test]
+    fn explains_exocets() {
+        let mut action = Action::new(Strategy::JuniorExocet);
+        action.erase_knowns(cell!("A7"), knowns!("1 2"));
+        action.clue_cell_for_knowns(Verdict::Primary, cell!("C1"), knowns!("7 8 9"));
+        action.clue_cell_for_knowns(Verdict::Primary, cell!("C2"), knowns!("6 7 8 9"));
+        action.clue_cells_for_knowns(Verdict::Secondary, cells!("A7 B4"), knowns!("6 7 8 9"));
+
+        assert_eq!(
+            "Junior Exocet on 6, 7, 8 and 9 with base C1 and C2 and targets A7 and B4, removes 1 and 2 from A7",
+            explain(&action)
+        );
+    }
+
+    #[test]
+    fn joins_lists() {
+        assert_eq!("", join(Vec::<&str>::new().into_iter()));
+        assert_eq!("A", join(["A"].into_iter()));
+        assert_eq!("A and B", join(["A", "B"].into_iter()));
+        assert_eq!("A, B and C", join(["A", "B", "C"].into_iter()));
+    }
+}
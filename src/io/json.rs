@@ -0,0 +1,625 @@
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::layout::{Cell, Known, KnownSet};
+use crate::puzzle::{Action, Board, Difficulty, Effects, Error, Strategy, Symmetry, Verdict};
+use crate::solve::{BruteForceResult, Rating, Resolution, SolutionCount};
+
+/// Incremented whenever a change to these documents would break existing readers.
+pub const FORMAT_VERSION: u32 = 1;
+
+/// Serializes a document to a single line of JSON tagged with the [`FORMAT_VERSION`].
+pub fn to_json<T: Serialize>(document: &T) -> String {
+    serde_json::to_string(&Versioned {
+        version: FORMAT_VERSION,
+        document,
+    })
+    .unwrap()
+}
+
+/// Deserializes a document produced by [`to_json`], rejecting other format versions.
+pub fn from_json<T: DeserializeOwned>(json: &str) -> Result<T, String> {
+    let versioned: Versioned<T> =
+        serde_json::from_str(json).map_err(|error| format!("Invalid JSON: {}", error))?;
+    if versioned.version != FORMAT_VERSION {
+        return Err(format!(
+            "Unsupported format version {}; expected {}",
+            versioned.version, FORMAT_VERSION
+        ));
+    }
+    Ok(versioned.document)
+}
+
+/// Adds the format version to the top level of a document.
+#[derive(Serialize, Deserialize)]
+pub struct Versioned<T> {
+    pub version: u32,
+    #[serde(flatten)]
+    pub document: T,
+}
+
+/// The complete state of a [`Board`]: its givens, solved cells and remaining candidates.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct BoardJson {
+    /// Packed givens with a period for every other cell.
+    pub givens: String,
+    /// Packed givens and solved cells with a period for every unsolved cell.
+    pub knowns: String,
+    /// Remaining candidates for each cell from A1 to J9, empty for known cells.
+    pub candidates: Vec<String>,
+}
+
+impl BoardJson {
+    pub fn new(board: &Board) -> Self {
+        let mut givens = String::with_capacity(81);
+        let mut knowns = String::with_capacity(81);
+        let mut candidates = Vec::with_capacity(81);
+
+        for cell in Cell::iter() {
+            let value = board.value(cell);
+            givens.push(if board.is_given(cell) {
+                value.label()
+            } else {
+                '.'
+            });
+            knowns.push(if value.is_known() { value.label() } else { '.' });
+            candidates.push(if value.is_known() {
+                String::new()
+            } else {
+                board.candidates(cell).iter().map(|k| k.label()).collect()
+            });
+        }
+
+        Self {
+            givens,
+            knowns,
+            candidates,
+        }
+    }
+
+    /// Rebuilds the board, failing if the state is incomplete or its knowns conflict.
+    /// Other errors such as cells without candidates are part of the state and allowed.
+    pub fn to_board(&self) -> Result<Board, String> {
+        let givens = self.givens.chars().collect::<Vec<char>>();
+        let knowns = self.knowns.chars().collect::<Vec<char>>();
+        if givens.len() != 81 || knowns.len() != 81 || self.candidates.len() != 81 {
+            return Err("Boards require 81 givens, knowns and candidates".to_string());
+        }
+
+        let mut board = Board::new();
+        let mut effects = Effects::new();
+
+        for cell in Cell::iter() {
+            let given = givens[cell.usize()];
+            let known = knowns[cell.usize()];
+            if ('1'..='9').contains(&given) {
+                if given != known {
+                    return Err(format!(
+                        "{} is given {} but known as {}",
+                        cell, given, known
+                    ));
+                }
+                board.set_given(cell, Known::from_char(given), &mut effects);
+            } else if ('1'..='9').contains(&known) {
+                board.set_known(cell, Known::from_char(known), &mut effects);
+            }
+        }
+
+        for cell in Cell::iter() {
+            if board.is_known(cell) {
+                continue;
+            }
+            let candidates = parse_digits(&self.candidates[cell.usize()])?;
+            if !board.candidates(cell).has_all(candidates) {
+                return Err(format!(
+                    "{} cannot have candidates {} with its peers",
+                    cell, candidates
+                ));
+            }
+            board.remove_candidates(cell, board.candidates(cell) - candidates, &mut effects);
+        }
+
+        let conflicts = effects
+            .errors_iter()
+            .filter(|error| matches!(error, Error::NotCandidate(..) | Error::AlreadySolved(..)))
+            .map(|error| error.to_string())
+            .collect::<Vec<String>>();
+        if conflicts.is_empty() {
+            Ok(board)
+        } else {
+            Err(conflicts.join(", "))
+        }
+    }
+}
+
+/// A single deduction: the strategy, the cells it solves and candidates it removes,
+/// and the clue cells that led to it.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ActionJson {
+    pub strategy: Strategy,
+    pub sets: Vec<SetJson>,
+    pub erases: Vec<EraseJson>,
+    pub clues: Vec<ClueJson>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SetJson {
+    pub cell: String,
+    pub digit: u8,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct EraseJson {
+    pub cell: String,
+    pub digits: Vec<u8>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ClueJson {
+    pub cell: String,
+    pub digit: u8,
+    pub verdict: Verdict,
+}
+
+impl ActionJson {
+    pub fn new(action: &Action) -> Self {
+        Self {
+            strategy: action.strategy(),
+            sets: action
+                .collect_sets()
+                .map(|(cell, known)| SetJson {
+                    cell: cell.label().to_string(),
+                    digit: digit(known),
+                })
+                .collect(),
+            erases: action
+                .collect_erases()
+                .map(|(cell, knowns)| EraseJson {
+                    cell: cell.label().to_string(),
+                    digits: knowns.iter().map(digit).collect(),
+                })
+                .collect(),
+            clues: action
+                .collect_clues()
+                .map(|(cell, known, verdict)| ClueJson {
+                    cell: cell.label().to_string(),
+                    digit: digit(known),
+                    verdict,
+                })
+                .collect(),
+        }
+    }
+
+    pub fn to_action(&self) -> Result<Action, String> {
+        let mut action = Action::new(self.strategy);
+
+        for set in &self.sets {
+            action.set(Cell::try_from(set.cell.as_str())?, to_known(set.digit)?);
+        }
+        for erase in &self.erases {
+            let cell = Cell::try_from(erase.cell.as_str())?;
+            for digit in &erase.digits {
+                action.erase(cell, to_known(*digit)?);
+            }
+        }
+        for clue in &self.clues {
+            action.clue_cell_for_known(
+                clue.verdict,
+                Cell::try_from(clue.cell.as_str())?,
+                to_known(clue.digit)?,
+            );
+        }
+
+        Ok(action)
+    }
+}
+
+/// Identifies an [`Error`] by its kind along with a readable description.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ErrorJson {
+    pub kind: String,
+    pub message: String,
+}
+
+impl ErrorJson {
+    pub fn new(error: &Error) -> Self {
+        let kind = match error {
+            Error::NotCandidate(..) => "NotCandidate",
+            Error::AlreadySolved(..) => "AlreadySolved",
+            Error::UnsolvableCell(..) => "UnsolvableCell",
+            Error::UnsolvableHouse(..) => "UnsolvableHouse",
+            Error::DeadlyRectangle(..) => "DeadlyRectangle",
+        };
+
+        Self {
+            kind: kind.to_string(),
+            message: error.to_string(),
+        }
+    }
+}
+
+/// The actions and errors collected in an [`Effects`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct EffectsJson {
+    pub actions: Vec<ActionJson>,
+    pub errors: Vec<ErrorJson>,
+}
+
+impl EffectsJson {
+    pub fn new(effects: &Effects) -> Self {
+        Self {
+            actions: actions_json(effects),
+            errors: effects.errors_iter().map(ErrorJson::new).collect(),
+        }
+    }
+
+    /// Rebuilds the actions; errors are descriptive only and are not restored.
+    pub fn to_effects(&self) -> Result<Effects, String> {
+        let mut effects = Effects::new();
+        for action in &self.actions {
+            effects.add_action(action.to_action()?);
+        }
+        Ok(effects)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ResolutionStatus {
+    /// The givens conflict, so the puzzle was never solved.
+    Invalid,
+    Canceled,
+    Failed,
+    Unsolved,
+    Solved,
+}
+
+/// The outcome of running the [`Solver`][`crate::solve::Solver`] on a puzzle
+/// with every action it applied and, if it failed, the action that broke the puzzle.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ResolutionJson {
+    pub status: ResolutionStatus,
+    pub difficulty: Difficulty,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rating: Option<RatingJson>,
+    pub board: BoardJson,
+    pub actions: Vec<ActionJson>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub failure: Option<FailureJson>,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct FailureJson {
+    pub action: ActionJson,
+    pub errors: Vec<ErrorJson>,
+}
+
+impl ResolutionJson {
+    pub fn new(resolution: &Resolution) -> Self {
+        let (status, board, applied, difficulty, failure) = match resolution {
+            Resolution::Canceled(board, applied, difficulty) => {
+                (ResolutionStatus::Canceled, board, applied, difficulty, None)
+            }
+            Resolution::Failed(board, applied, difficulty, action, errors) => (
+                ResolutionStatus::Failed,
+                board,
+                applied,
+                difficulty,
+                Some(FailureJson {
+                    action: ActionJson::new(action),
+                    errors: errors.errors_iter().map(ErrorJson::new).collect(),
+                }),
+            ),
+            Resolution::Unsolved(board, applied, difficulty) => {
+                (ResolutionStatus::Unsolved, board, applied, difficulty, None)
+            }
+            Resolution::Solved(board, applied, difficulty) => {
+                (ResolutionStatus::Solved, board, applied, difficulty, None)
+            }
+        };
+
+        Self {
+            status,
+            difficulty: *difficulty,
+            rating: resolution.rating().map(RatingJson::new),
+            board: BoardJson::new(board),
+            actions: actions_json(applied),
+            failure,
+        }
+    }
+}
+
+/// A starting puzzle along with its solution and the actions taken to solve it.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PuzzleJson {
+    pub puzzle: String,
+    pub solution: String,
+    pub clues: usize,
+    pub difficulty: Difficulty,
+    pub rating: RatingJson,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub actions: Vec<ActionJson>,
+}
+
+impl PuzzleJson {
+    pub fn new(start: &Board, solution: &Board, difficulty: Difficulty, actions: &Effects) -> Self {
+        Self {
+            puzzle: start.packed_string(),
+            solution: solution.packed_string(),
+            clues: start.known_count(),
+            difficulty,
+            rating: RatingJson::new(Rating::new(actions)),
+            seed: None,
+            actions: actions_json(actions),
+        }
+    }
+
+    /// Records the seed that generated the puzzle so it can be recreated.
+    pub fn with_seed(self, seed: u64) -> Self {
+        Self {
+            seed: Some(seed),
+            ..self
+        }
+    }
+
+    /// Drops the actions while keeping the rating computed from them.
+    pub fn without_actions(self) -> Self {
+        Self {
+            actions: vec![],
+            ..self
+        }
+    }
+}
+
+/// The SE-style [`Rating`] of a solved puzzle.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct RatingJson {
+    pub hardest: f32,
+    pub hard_steps: usize,
+}
+
+impl RatingJson {
+    pub fn new(rating: Rating) -> Self {
+        Self {
+            hardest: rating.hardest(),
+            hard_steps: rating.hard_steps(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum BruteForceStatus {
+    /// The givens conflict, so brute force was never attempted.
+    Invalid,
+    AlreadySolved,
+    TooFewKnowns,
+    Unsolvable,
+    Canceled,
+    Solved,
+    Multiple,
+}
+
+/// The outcome of [`find_brute_force`][`crate::solve::find_brute_force`]
+/// with each solution found and any cells left without candidates.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct BruteForceJson {
+    pub status: BruteForceStatus,
+    pub solutions: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub empty_cells: Vec<String>,
+}
+
+impl BruteForceJson {
+    pub fn new(board: &Board, result: &BruteForceResult) -> Self {
+        let mut solutions = vec![];
+        let mut empty_cells = vec![];
+        let status = match result {
+            BruteForceResult::AlreadySolved => {
+                solutions.push(board.packed_string());
+                BruteForceStatus::AlreadySolved
+            }
+            BruteForceResult::TooFewKnowns => BruteForceStatus::TooFewKnowns,
+            BruteForceResult::UnsolvableCells(cells) => {
+                empty_cells = cells.iter().map(|cell| cell.label().to_string()).collect();
+                BruteForceStatus::Unsolvable
+            }
+            BruteForceResult::Canceled => BruteForceStatus::Canceled,
+            BruteForceResult::Unsolvable => BruteForceStatus::Unsolvable,
+            BruteForceResult::Solved(solution) => {
+                solutions.push(solution.packed_string());
+                BruteForceStatus::Solved
+            }
+            BruteForceResult::MultipleSolutions(found) => {
+                solutions = found.iter().map(|board| board.packed_string()).collect();
+                BruteForceStatus::Multiple
+            }
+        };
+
+        Self {
+            status,
+            solutions,
+            empty_cells,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SolutionCountStatus {
+    /// The givens conflict, so no solutions were counted.
+    Invalid,
+    Exact,
+    AtLeast,
+    Canceled,
+}
+
+/// The outcome of [`count_solutions`][`crate::solve::count_solutions`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct SolutionCountJson {
+    pub status: SolutionCountStatus,
+    pub count: usize,
+}
+
+impl SolutionCountJson {
+    pub fn new(count: SolutionCount) -> Self {
+        let status = match count {
+            SolutionCount::Exact(_) => SolutionCountStatus::Exact,
+            SolutionCount::AtLeast(_) => SolutionCountStatus::AtLeast,
+            SolutionCount::Canceled(_) => SolutionCountStatus::Canceled,
+        };
+
+        Self {
+            status,
+            count: count.count(),
+        }
+    }
+}
+
+/// A puzzle moved by a [`Symmetry`] along with the symmetry that restores it.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TransformJson {
+    pub puzzle: String,
+    pub symmetry: String,
+    pub inverse: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub seed: Option<u64>,
+}
+
+impl TransformJson {
+    pub fn new(board: &Board, symmetry: &Symmetry, seed: Option<u64>) -> Self {
+        Self {
+            puzzle: symmetry.apply(board).packed_string(),
+            symmetry: symmetry.to_string(),
+            inverse: symmetry.inverse().to_string(),
+            seed,
+        }
+    }
+}
+
+/// Returns a readable message for each error in the effects.
+pub fn describe_errors(effects: &Effects) -> Vec<String> {
+    effects
+        .errors_iter()
+        .map(|error| error.to_string())
+        .collect()
+}
+
+fn actions_json(effects: &Effects) -> Vec<ActionJson> {
+    effects.actions().iter().map(ActionJson::new).collect()
+}
+
+const fn digit(known: Known) -> u8 {
+    known.usize() as u8 + 1
+}
+
+fn to_known(digit: u8) -> Result<Known, String> {
+    if (1..=9).contains(&digit) {
+        Ok(Known::new(digit))
+    } else {
+        Err(format!("Invalid digit {}", digit))
+    }
+}
+
+fn parse_digits(digits: &str) -> Result<KnownSet, String> {
+    digits.chars().try_fold(KnownSet::empty(), |set, c| {
+        Known::try_from(c).map(|known| set + known)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::io::{Parse, Parser};
+    use crate::layout::cells::cell::cell;
+    use crate::layout::values::known::known;
+    use crate::solve::{Solver, Timings};
+
+    use super::*;
+
+    #[test]
+    fn board_round_trips_givens_knowns_and_candidates() {
+        let board = Parse::wiki().stop_on_error().parse_simple(
+            "811003080g44g02044090g21g002441080444104g0108020030g080g201004098040g10202g04021100g05088104800840g0030h1120g0090402211080400h10400h8005082003g12102800h41g0090410",
+        );
+
+        let json = to_json(&BoardJson::new(&board));
+        let parsed: BoardJson = from_json(&json).unwrap();
+
+        assert_eq!(board, parsed.to_board().unwrap());
+    }
+
+    #[test]
+    fn board_rejects_candidates_removed_by_peers() {
+        let mut json = BoardJson::new(&Parse::packed().parse_simple("1"));
+        json.candidates[1] = "12".to_string();
+
+        assert!(json.to_board().is_err());
+    }
+
+    #[test]
+    fn action_round_trips_sets_erases_and_clues() {
+        let mut action = Action::new(Strategy::XWing);
+        action.erase_cells(cell!("B2") + cell!("J2"), known!("7"));
+        action.clue_cells_for_known(
+            Verdict::Primary,
+            cell!("A1") + cell!("A5") + cell!("E1") + cell!("E5"),
+            known!("7"),
+        );
+        action.set(cell!("C3"), known!("4"));
+
+        let json = to_json(&ActionJson::new(&action));
+        let parsed: ActionJson = from_json(&json).unwrap();
+
+        assert_eq!(action, parsed.to_action().unwrap());
+    }
+
+    #[test]
+    fn rejects_other_versions() {
+        let json = r#"{"version":0,"givens":"","knowns":"","candidates":[]}"#;
+
+        assert!(from_json::<BoardJson>(json).is_err());
+    }
+
+    #[test]
+    fn resolution_includes_every_action() {
+        let board = Parse::packed().parse_simple(
+            "..2...376 .1..3.5.. .......9. 9..85...1 ...3.4... 2...97..3 .8....... ..3.4..6. 147...2..",
+        );
+        let resolution = Solver::new(false).solve(&board, &Effects::new(), &mut Timings::new());
+        let Resolution::Solved(solution, actions, _) = &resolution else {
+            panic!("puzzle not solved");
+        };
+
+        let json = ResolutionJson::new(&resolution);
+
+        assert_eq!(ResolutionStatus::Solved, json.status);
+        assert_eq!(solution.packed_string(), json.board.knowns);
+        assert_eq!(actions.action_count(), json.actions.len());
+        assert!(json.failure.is_none());
+    }
+
+    #[test]
+    fn solution_count_reports_capped_counts() {
+        let json = to_json(&SolutionCountJson::new(SolutionCount::AtLeast(1_000)));
+
+        assert_eq!(r#"{"version":1,"status":"at-least","count":1000}"#, json);
+    }
+
+    #[test]
+    fn transform_reports_the_inverse() {
+        let board = Parse::packed().parse_simple("1.......2");
+        let json = to_json(&TransformJson::new(&board, &Symmetry::rotate(2), None));
+
+        assert_eq!(
+            format!(
+                r#"{{"version":1,"puzzle":"{}2.......1","symmetry":"{}","inverse":"{}"}}"#,
+                ".".repeat(72),
+                "987654321/987654321/123456789",
+                "987654321/987654321/123456789"
+            ),
+            json
+        );
+    }
+}
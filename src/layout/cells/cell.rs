@@ -30,6 +30,7 @@ impl Cell {
         Self::from_coords(row.coord(), column.coord())
     }
 
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(label: &str) -> Self {
         Self(index_from_label(label))
     }
@@ -178,6 +179,7 @@ impl fmt::Display for Cell {
     }
 }
 
+#[derive(Default)]
 pub struct CellIter(u8);
 
 impl CellIter {
@@ -371,6 +371,12 @@ impl CellSet {
             .fold(CellSet::full(), |set, cell| set & cell.peers())
     }
 
+    /// Returns the peers of any member of this set.
+    pub fn union_peers(&self) -> CellSet {
+        self.iter()
+            .fold(CellSet::empty(), |set, cell| set | cell.peers())
+    }
+
     /// Returns an iterator over the members of this set in row-then-column order.
     pub const fn iter(&self) -> CellIter {
         CellIter {
@@ -689,6 +695,7 @@ impl Iterator for BitIter {
 impl FusedIterator for BitIter {}
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison, clippy::useless_vec)]
 mod tests {
     use crate::layout::cells::cell::cell;
     use crate::layout::houses::coord::{coord, Coord};
@@ -97,6 +97,7 @@ impl House {
         self.is_row() && self.coord.u8() == 0
     }
 
+    #[allow(clippy::manual_is_multiple_of)]
     pub const fn is_block_top(&self) -> bool {
         self.is_row() && self.coord.u8() % 3 == 0
     }
@@ -113,6 +114,7 @@ impl House {
         self.is_column() && self.coord.u8() == 0
     }
 
+    #[allow(clippy::manual_is_multiple_of)]
     pub const fn is_block_left(&self) -> bool {
         self.is_column() && self.coord.u8() % 3 == 0
     }
@@ -302,6 +304,12 @@ impl HousesIter {
     }
 }
 
+impl Default for HousesIter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Iterator for HousesIter {
     type Item = House;
 
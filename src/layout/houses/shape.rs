@@ -92,6 +92,7 @@ impl fmt::Debug for Shape {
     }
 }
 
+#[derive(Default)]
 pub struct ShapeIter(u8);
 
 impl ShapeIter {
@@ -31,6 +31,7 @@ impl Known {
         }
     }
 
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(label: &str) -> Self {
         match Self::try_from(label) {
             Ok(known) => known,
@@ -109,6 +110,7 @@ impl fmt::Display for Known {
     }
 }
 
+#[derive(Default)]
 pub struct KnownIter(u8);
 
 impl KnownIter {
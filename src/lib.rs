@@ -0,0 +1,56 @@
+//! A Sudoku engine for building, parsing, solving and generating puzzles.
+//!
+//! The command-line player and tools are built on top of this library,
+//! but it may be used on its own by any Rust application.
+//!
+//! - [`layout`] defines the cells, houses and digits that make up a board
+//!   along with the bitsets used to manipulate them.
+//! - [`puzzle`] provides the [`Board`] itself and the [`Action`]s, [`Effects`]
+//!   and [`Changer`] used to modify it.
+//! - [`io`] can [`Parse`] boards from and [`Format`] boards to several string formats.
+//! - [`solve`] holds the [`Solver`] for stepping through a solution one deduction
+//!   at a time using the available [`TECHNIQUES`], and [`find_brute_force`]
+//!   to find every solution to a puzzle.
+//! - [`build`] uses a [`Generator`] to create complete solutions and a [`Finder`]
+//!   to remove clues from them to produce new puzzles, and a [`Minimizer`]
+//!   removes redundant clues from existing puzzles.
+//!
+//! ```
+//! use sudoku_rust::{Parse, Parser, Resolution, Solver, Timings};
+//!
+//! let board = Parse::packed().parse_simple(
+//!     "..2...376 .1..3.5.. .......9. 9..85...1 ...3.4... 2...97..3 .8....... ..3.4..6. 147...2..",
+//! );
+//! let solver = Solver::new(false);
+//! let resolution = solver.solve(&board, &Default::default(), &mut Timings::new());
+//!
+//! assert!(matches!(resolution, Resolution::Solved(..)));
+//! ```
+
+#![allow(
+    clippy::collapsible_else_if,
+    clippy::collapsible_if,
+    clippy::too_many_arguments,
+    dead_code
+)]
+
+pub use build::{ClueSymmetry, Finder, Generator, Minimizer, Target};
+pub use io::{Format, Parse, Parser};
+pub use layout::{Cell, CellSet, House, Known, KnownSet, Value};
+pub use puzzle::{
+    Action, Board, ChangeResult, Changer, Difficulty, Effects, Options, Strategy, Symmetry,
+};
+pub use solve::{
+    count_solutions, find_brute_force, BruteForceResult, Rating, Resolution, SolutionCount, Solver,
+    Timings, TECHNIQUES,
+};
+
+pub mod build;
+pub mod io;
+pub mod layout;
+pub mod puzzle;
+pub mod solve;
+pub mod symbols;
+
+#[cfg(test)]
+mod testing;
@@ -7,20 +7,16 @@
 
 use clap::{Parser, Subcommand};
 
+use sudoku_rust::io::create_signal;
+
 use crate::commands::{
-    bingo, create_puzzle, extract_patterns, find_solutions, solve_puzzles, start_player, BingoArgs,
-    CreateArgs, ExtractArgs, FindArgs, PlayArgs, SolveArgs,
+    bingo, canonicalize_puzzles, create_puzzle, explain_puzzle, extract_patterns, find_solutions,
+    minimize_puzzles, solve_puzzles, start_player, start_server, transform_puzzles, BingoArgs,
+    CanonArgs, CreateArgs, ExplainArgs, ExtractArgs, FindArgs, MinimizeArgs, PlayArgs, ServeArgs,
+    SolveArgs, TransformArgs,
 };
-use crate::io::create_signal;
 
-mod build;
 mod commands;
-mod io;
-mod layout;
-mod puzzle;
-mod solve;
-mod symbols;
-mod testing;
 
 /// A command-line sudoku player, generator and solver written in Rust
 #[derive(Debug, Parser)]
@@ -67,6 +63,14 @@ enum Commands {
     #[clap(alias = "s", verbatim_doc_comment)]
     Solve(SolveArgs),
 
+    /// Explain each step taken to solve a puzzle
+    ///
+    /// Describes each deduction in plain English and prints the board
+    /// before it with the cells involved highlighted. Use `--brief`
+    /// to print only the descriptions.
+    #[clap(alias = "x", verbatim_doc_comment)]
+    Explain(ExplainArgs),
+
     /// Brute force a puzzle using Bowman's Bingo
     ///
     /// Finds all possible solutions for a starting puzzle, up to a maximum.
@@ -90,6 +94,40 @@ enum Commands {
     #[clap(alias = "e", verbatim_doc_comment)]
     Extract(ExtractArgs),
 
+    /// Print the canonical form of each puzzle from STDIN
+    ///
+    /// Puzzles that differ only by relabeling digits, swapping rows or columns
+    /// within a band or stack, swapping bands or stacks, or transposing share
+    /// a canonical form. Use `--group` to count how many puzzles from STDIN
+    /// are essentially the same, and `--pattern` to compare only the cells
+    /// holding givens.
+    #[clap(verbatim_doc_comment)]
+    Canon(CanonArgs),
+
+    /// Transform puzzles into essentially identical variants
+    ///
+    /// Each puzzle given on the command line or read from STDIN is moved by
+    /// a random symmetry unless you specify one with `--apply`, `--transpose`,
+    /// `--rotate` or `--reflect`. Use `--seed` to repeat the random symmetries.
+    ///
+    /// Each transformed puzzle is printed with the inverse symmetry,
+    /// which maps its solution back to the original when passed to `--apply`.
+    #[clap(alias = "t", verbatim_doc_comment)]
+    Transform(TransformArgs),
+
+    /// Remove redundant clues to make puzzles minimal
+    ///
+    /// A clue is redundant when the puzzle keeps a single solution without it.
+    /// Use `--check` to list the redundant clues of each puzzle without removing
+    /// them, and `--hardest` to keep clues whose removal would make the puzzle
+    /// harder than a difficulty.
+    ///
+    /// Each clue is removed in turn if it is still redundant, so the result
+    /// depends on the order. Use `--tries` to repeat with random orders
+    /// and keep the puzzle with the fewest clues.
+    #[clap(alias = "m", verbatim_doc_comment)]
+    Minimize(MinimizeArgs),
+
     /// Find a solvable set of clues using patterns from STDIN
     ///
     /// Redirect a file containing patterns to this command, and it will print each
@@ -100,6 +138,22 @@ enum Commands {
     /// Add the `--actions` option to print the strategies employed to solve each puzzle.
     #[clap(alias = "f", verbatim_doc_comment)]
     Find(FindArgs),
+
+    /// Serve a JSON API for creating, solving and verifying puzzles
+    ///
+    /// Each endpoint accepts a POST with a JSON body and returns JSON.
+    ///
+    ///   /create  generate a new puzzle with optional clues, time and solution
+    ///   /solve   solve a puzzle step-by-step, returning each deduction
+    ///   /verify  check that a puzzle has a single solution using brute force
+    ///   /hints   list the deductions of the first technique that applies
+    ///
+    /// Pass puzzles as packed givens in "puzzle", as the full board state
+    /// including candidates in "wiki", or as a "board" with "givens", "knowns"
+    /// and "candidates" returned by an earlier response. Press Ctrl-C to stop
+    /// the server.
+    #[clap(verbatim_doc_comment)]
+    Serve(ServeArgs),
 }
 
 /// Executes the specified subcommand.
@@ -112,9 +166,14 @@ fn main() {
             Commands::Play(args) => start_player(args),
             Commands::Create(args) => create_puzzle(args),
             Commands::Solve(args) => solve_puzzles(args),
+            Commands::Explain(args) => explain_puzzle(args),
             Commands::Bingo(args) => bingo(args),
             Commands::Extract(args) => extract_patterns(args),
+            Commands::Canon(args) => canonicalize_puzzles(args),
+            Commands::Transform(args) => transform_puzzles(args),
+            Commands::Minimize(args) => minimize_puzzles(args),
             Commands::Find(args) => find_solutions(args),
+            Commands::Serve(args) => start_server(args),
         }
     } else {
         start_player(PlayArgs::new());
@@ -27,6 +27,7 @@
 
 pub use action::Action;
 pub use board::{Board, Change};
+pub use canonical::{canonical_board, canonical_pattern};
 pub use changer::{ChangeResult, Changer};
 pub use clues::{Clues, Verdict};
 pub use effects::Effects;
@@ -34,9 +35,11 @@ pub use error::Error;
 pub use options::Options;
 pub use pseudo_cell::PseudoCell;
 pub use strategy::{Difficulty, Strategy};
+pub use symmetry::Symmetry;
 
 mod action;
 mod board;
+mod canonical;
 mod changer;
 mod clues;
 mod effects;
@@ -44,3 +47,4 @@ mod error;
 mod options;
 mod pseudo_cell;
 mod strategy;
+mod symmetry;
@@ -0,0 +1,215 @@
+use std::cmp::Ordering;
+
+use crate::layout::{Cell, CellSet, Known};
+
+use super::{Board, Effects};
+
+/// Every ordering of the three rows in a band, columns in a stack,
+/// or bands and stacks in the grid.
+const ORDERS: [[usize; 3]; 6] = [
+    [0, 1, 2],
+    [0, 2, 1],
+    [1, 0, 2],
+    [1, 2, 0],
+    [2, 0, 1],
+    [2, 1, 0],
+];
+
+/// Returns the essentially identical puzzle that sorts first
+/// when packed with zeros for unknown cells.
+///
+/// Two puzzles are isomorphs when one can be turned into the other
+/// by relabeling digits, swapping rows within a band or columns within a stack,
+/// swapping bands or stacks, and transposing the grid. Since these preserve
+/// every row, column and block, isomorphs share their solutions and difficulty,
+/// and comparing canonical forms detects them.
+///
+/// Every known cell becomes a given in the returned board.
+pub fn canonical_board(board: &Board) -> Board {
+    let mut values = [0; 81];
+    for (cell, known) in board.known_iter() {
+        values[cell.usize()] = known.usize() as u8 + 1;
+    }
+
+    let mut canonical = Board::new();
+    let mut effects = Effects::new();
+    for (i, value) in canonical_values(&values, true).into_iter().enumerate() {
+        if value != 0 {
+            canonical.set_given(Cell::new(i as u8), Known::new(value), &mut effects);
+        }
+    }
+    canonical
+}
+
+/// Returns the pattern of cells that sorts first among those
+/// that may be reached by the same moves as [`canonical_board`],
+/// ignoring the digits.
+pub fn canonical_pattern(pattern: CellSet) -> CellSet {
+    let mut values = [0; 81];
+    for cell in pattern {
+        values[cell.usize()] = 1;
+    }
+
+    canonical_values(&values, false)
+        .into_iter()
+        .enumerate()
+        .filter(|(_, value)| *value != 0)
+        .map(|(i, _)| Cell::new(i as u8))
+        .collect()
+}
+
+fn canonical_values(values: &[u8; 81], relabel: bool) -> [u8; 81] {
+    let mut transposed = [0; 81];
+    for (i, value) in values.iter().enumerate() {
+        transposed[9 * (i % 9) + i / 9] = *value;
+    }
+
+    let mut search = Search {
+        rows: [[0; 9]; 9],
+        relabel,
+        current: [0; 81],
+        best: [u8::MAX; 81],
+    };
+    for grid in [values, &transposed] {
+        for stacks in ORDERS {
+            for left in ORDERS {
+                for middle in ORDERS {
+                    for right in ORDERS {
+                        let mut columns = [0; 9];
+                        for (i, within) in [left, middle, right].into_iter().enumerate() {
+                            for j in 0..3 {
+                                columns[3 * i + j] = 3 * stacks[i] + within[j];
+                            }
+                        }
+                        for (r, row) in search.rows.iter_mut().enumerate() {
+                            for (c, column) in columns.iter().enumerate() {
+                                row[c] = grid[9 * r + column];
+                            }
+                        }
+                        search.place_row(0, 0, 0, [0; 10], 1);
+                    }
+                }
+            }
+        }
+    }
+    search.best
+}
+
+/// Orders the rows of one column arrangement, abandoning any ordering
+/// as soon as it sorts after the best found so far.
+struct Search {
+    rows: [[u8; 9]; 9],
+    relabel: bool,
+    current: [u8; 81],
+    best: [u8; 81],
+}
+
+impl Search {
+    /// Tries each row that may fill the position, where `band` holds
+    /// the rows above it in the same band and `labels` relabels the digits
+    /// in the order they first appear.
+    #[allow(clippy::manual_is_multiple_of)]
+    fn place_row(&mut self, position: usize, band: usize, used: u16, labels: [u8; 10], next: u8) {
+        if position == 9 {
+            if self.current < self.best {
+                self.best = self.current;
+            }
+            return;
+        }
+
+        for row in 0..9 {
+            if used & (1 << row) != 0 {
+                continue;
+            }
+            if position % 3 == 0 {
+                // start the next band with any row from an unused band
+                if used & (0b111 << (3 * (row / 3))) != 0 {
+                    continue;
+                }
+            } else if row / 3 != band {
+                continue;
+            }
+
+            let (mut labels, mut next) = (labels, next);
+            let start = 9 * position;
+            for (c, value) in self.rows[row].into_iter().enumerate() {
+                self.current[start + c] = match value {
+                    0 => 0,
+                    _ if !self.relabel => value,
+                    _ => {
+                        if labels[value as usize] == 0 {
+                            labels[value as usize] = next;
+                            next += 1;
+                        }
+                        labels[value as usize]
+                    }
+                };
+            }
+
+            let end = start + 9;
+            if self.current[..end].cmp(&self.best[..end]) != Ordering::Greater {
+                self.place_row(position + 1, row / 3, used | (1 << row), labels, next);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::io::{Parse, Parser};
+
+    use super::*;
+
+    const PUZZLE: &str =
+        "..2...376.1..3.5.........9.9..85...1...3.4...2...97..3.8.........3.4..6.147...2..";
+
+    /// Transposes the puzzle, swaps its first two bands and the last two columns
+    /// of its first stack, and swaps each digit with the next one.
+    fn isomorph(packed: &str) -> String {
+        let chars = packed.chars().collect::<Vec<_>>();
+        let rows = [3, 4, 5, 0, 1, 2, 6, 7, 8];
+        let columns = [0, 2, 1, 3, 4, 5, 6, 7, 8];
+        (0..81)
+            .map(|i| chars[9 * columns[i % 9] + rows[i / 9]])
+            .map(|c| match c.to_digit(10) {
+                Some(digit) => char::from_digit(digit % 9 + 1, 10).unwrap(),
+                None => c,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn isomorphs_share_canonical_form() {
+        let parser = Parse::packed();
+        let board = parser.parse_simple(PUZZLE);
+        let other = parser.parse_simple(&isomorph(PUZZLE));
+        assert_ne!(board.packed_string(), other.packed_string());
+
+        let canonical = canonical_board(&board);
+        assert_eq!(
+            canonical.packed_string(),
+            canonical_board(&other).packed_string()
+        );
+        assert_eq!(canonical, canonical_board(&canonical));
+        assert_eq!(board.known_count(), canonical.known_count());
+    }
+
+    #[test]
+    fn canonical_form_sorts_first() {
+        let parser = Parse::packed();
+        let canonical = canonical_board(&parser.parse_simple(PUZZLE)).packed_string();
+
+        assert!(canonical.as_str() <= PUZZLE);
+        assert!(canonical <= isomorph(PUZZLE));
+    }
+
+    #[test]
+    fn patterns_ignore_digits() {
+        let pattern = CellSet::new_from_pattern(PUZZLE);
+        let other = CellSet::new_from_pattern(&isomorph(PUZZLE));
+
+        let canonical = canonical_pattern(pattern);
+        assert_eq!(canonical, canonical_pattern(other));
+        assert_eq!(pattern.len(), canonical.len());
+    }
+}
@@ -59,6 +59,12 @@ impl Changer {
 
     /// Applies the given action and any automatic actions it creates.
     pub fn apply(&self, board: &Board, action: &Action) -> ChangeResult {
+        self.apply_to_depth(board, action, usize::MAX)
+    }
+
+    /// Applies the given action and at most `depth` rounds
+    /// of the automatic actions that follow from it.
+    pub fn apply_to_depth(&self, board: &Board, action: &Action, depth: usize) -> ChangeResult {
         let mut after = *board;
         let mut effects = Effects::new();
 
@@ -66,13 +72,13 @@ impl Changer {
         if self.options.stop_on_error && effects.has_errors() {
             ChangeResult::Invalid(Box::new(*board), Box::new(after), action.clone(), effects)
         } else {
-            self.apply_all_changed(board, &after, &effects, change)
+            self.apply_all_changed(board, &after, &effects, change, depth)
         }
     }
 
     /// Applies all automatic actions to the given board.
     pub fn apply_all(&self, board: &Board, actions: &Effects) -> ChangeResult {
-        self.apply_all_changed(board, board, actions, Change::None)
+        self.apply_all_changed(board, board, actions, Change::None, usize::MAX)
     }
 
     fn apply_all_changed(
@@ -81,12 +87,14 @@ impl Changer {
         board: &Board,
         actions: &Effects,
         mut change: Change,
+        mut depth: usize,
     ) -> ChangeResult {
         let mut good = *board;
         let mut applying = actions.clone();
         let mut unapplied = Effects::new();
 
-        while applying.has_actions() {
+        while applying.has_actions() && depth > 0 {
+            depth -= 1;
             let mut next = Effects::new();
             for action in applying.actions() {
                 if self.options.should_apply(action.strategy()) {
@@ -119,6 +127,8 @@ impl Changer {
 
             applying = next;
         }
+        // actions beyond the depth limit are left for the caller
+        unapplied.take_actions(applying);
 
         if change.changed() {
             // errors are treated as valid when not stopping for them
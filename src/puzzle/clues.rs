@@ -4,11 +4,14 @@ use std::fmt;
 use std::fmt::Write;
 
 use colored::Colorize;
+use serde::{Deserialize, Serialize};
 
 use crate::layout::{Cell, CellSet, Known, KnownSet};
 use crate::symbols::EMPTY_SET;
 
-#[derive(Clone, Copy, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd)]
+#[derive(
+    Clone, Copy, Debug, Default, Hash, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize,
+)]
 pub enum Verdict {
     #[default]
     None,
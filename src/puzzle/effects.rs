@@ -3,10 +3,10 @@ use std::collections::HashMap;
 
 use crate::layout::{Cell, CellSet, Known, KnownSet};
 
-use super::{Action, Board, Change, Error, Strategy};
+use super::{Action, Board, Change, Difficulty, Error, Strategy};
 
 /// Collects actions and errors encountered while modifying a board.
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct Effects {
     errors: Vec<Error>,
     actions: Vec<Action>,
@@ -72,6 +72,15 @@ impl Effects {
             })
     }
 
+    /// Returns the highest difficulty of the strategies used by the actions.
+    pub fn difficulty(&self) -> Difficulty {
+        self.actions
+            .iter()
+            .map(|action| action.strategy().difficulty())
+            .max()
+            .unwrap_or(Difficulty::Trivial)
+    }
+
     pub fn clear_actions(&mut self) {
         self.actions = vec![];
     }
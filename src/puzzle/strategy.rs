@@ -1,4 +1,7 @@
 use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
 
 /// Identifies the logic used to solve cells and remove candidates.
 ///
@@ -29,7 +32,7 @@ use std::fmt;
 /// - have fun
 /// - exercise my brain
 /// - Create a generalized solver using inference chains
-#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum Strategy {
     // these become the Clues; copy and generalize for Strategy
     /// The player or parser has provided a given (clue).
@@ -85,6 +88,20 @@ pub enum Strategy {
     Swordfish, // (Known, mains HouseSet, crosses HouseSet)
     Jellyfish, // (Known, mains HouseSet, crosses HouseSet)
 
+    /// A fish with extra candidates in one of its base houses (the fins)
+    /// may remove the candidate only from cover cells that see every fin.
+    FinnedXWing, // (Known, mains HouseSet, crosses HouseSet, fins CellSet)
+    /// A finned fish that would be degenerate without its fins.
+    SashimiXWing, // (Known, mains HouseSet, crosses HouseSet, fins CellSet)
+    FinnedSwordfish,  // (Known, mains HouseSet, crosses HouseSet, fins CellSet)
+    SashimiSwordfish, // (Known, mains HouseSet, crosses HouseSet, fins CellSet)
+    FinnedJellyfish,  // (Known, mains HouseSet, crosses HouseSet, fins CellSet)
+    SashimiJellyfish, // (Known, mains HouseSet, crosses HouseSet, fins CellSet)
+    /// A fish using boxes with either rows or columns for its base or cover houses.
+    FrankenFish, // (Known, Vec<House>, Vec<House>, fins CellSet)
+    /// A fish mixing rows and columns in its base or cover houses.
+    MutantFish, // (Known, Vec<House>, Vec<House>, fins CellSet)
+
     Bug,                // (Cell, Cell, Cell)
     AvoidableRectangle, // (CellSet) - all unsolved cells
     TwoStringKite,      // (Known, Vec<Cell>)
@@ -93,8 +110,12 @@ pub enum Strategy {
     YWing,              // (Known, pivot Cell, arms (Cell, Cell))
     XYZWing,            // (Known, pivot Cell, arms (Cell, Cell))
     WXYZWing,           // (Known, pivot Cell, arms (Cell, Cell, Cell))
+    WWing,              // (Known, (Cell, Cell), strong link (Cell, Cell))
+    VWXYZWing,          // (Known, CellSet)
+    UVWXYZWing,         // (Known, CellSet)
 
     XYChain,                 // (Known, Vec<Cell>)
+    RemotePairs,             // (KnownSet, Vec<Cell>)
     UniqueRectangle,         // (KnownSet, Cell, Cell, Cell, Cell)
     AlmostUniqueRectangle,   // (KnownSet, Cell, Cell, Cell, Cell)
     Fireworks,               // (KnownSet, Cell, Cell, Cell)
@@ -103,10 +124,116 @@ pub enum Strategy {
 
     EmptyRectangle, // (Known, Block, Row, Column, Cell) - CellSet instead of three houses
 
+    /// Cells where a block and line intersect locked together with cells
+    /// from the rest of each may remove those candidates from both.
+    SueDeCoq, // (KnownSet, intersection CellSet, line CellSet, block CellSet)
+
+    /// Two single-digit coloring clusters that see each other
+    /// may remove the digit from cells that see one color of each.
+    MultiColoring, // (Known, (CellSet, CellSet), (CellSet, CellSet))
+    /// Coloring candidates of every digit through bi-value cells and bi-location houses
+    /// may find a color that must be false or candidates that see both colors.
+    Medusa3D, // (Vec<(Cell, Known)>, Vec<(Cell, Known)>)
+
+    /// A single-digit chain or loop alternating strong and weak links between cells.
+    XCycle, // (Known, Vec<Cell>)
+    /// A chain or loop alternating strong and weak links between any candidates.
+    AlternatingInferenceChain, // (Vec<(Cell, Known)>)
+
+    /// Two almost locked sets sharing a restricted common candidate
+    /// may remove their other common candidates from cells that see them all.
+    AlsXZ, // (Known, CellSet, CellSet)
+    /// Two almost locked sets joined to a third by different restricted commons
+    /// may remove their common candidate from cells that see them all.
+    AlsXYWing, // (Known, pivot CellSet, CellSet, CellSet)
+    /// A stem cell with an almost locked set for each candidate
+    /// may remove their common candidate from cells that see them all.
+    DeathBlossom, // (Known, stem Cell, Vec<CellSet>)
+    /// A candidate that no valid placement of its digit covers may be removed.
+    PatternOverlay, // (Known, Vec<CellSet>)
+
+    /// A candidate that leads to a contradiction when assumed to be true may be removed.
+    Nishio, // (Known, Cell)
+    /// Whatever follows from every candidate in a cell must be true.
+    CellForcingChain, // (Cell, Vec<(Cell, Known)>)
+    /// Whatever follows from every cell with a candidate in a house must be true.
+    UnitForcingChain, // (Known, House, Vec<(Cell, Known)>)
+    /// Whatever follows from a candidate being both true and false must be true.
+    DigitForcingChain, // (Known, Cell, Vec<(Cell, Known)>)
+
+    /// Two base cells in a block segment whose digits must reappear
+    /// in two target cells in the other blocks of the band.
+    JuniorExocet, // (KnownSet, base CellSet, target CellSet)
+
     BruteForce,
 }
 
 impl Strategy {
+    /// Every strategy in declaration order.
+    pub const ALL: [Strategy; 61] = [
+        Self::Given,
+        Self::Solve,
+        Self::Erase,
+        Self::Peer,
+        Self::NakedSingle,
+        Self::HiddenSingle,
+        Self::NakedPair,
+        Self::HiddenPair,
+        Self::NakedTriple,
+        Self::HiddenTriple,
+        Self::NakedQuad,
+        Self::HiddenQuad,
+        Self::IntersectionRemoval,
+        Self::PointingPair,
+        Self::PointingTriple,
+        Self::BoxLineReduction,
+        Self::XWing,
+        Self::Swordfish,
+        Self::Jellyfish,
+        Self::FinnedXWing,
+        Self::SashimiXWing,
+        Self::FinnedSwordfish,
+        Self::SashimiSwordfish,
+        Self::FinnedJellyfish,
+        Self::SashimiJellyfish,
+        Self::FrankenFish,
+        Self::MutantFish,
+        Self::Bug,
+        Self::AvoidableRectangle,
+        Self::TwoStringKite,
+        Self::SinglesChain,
+        Self::Skyscraper,
+        Self::YWing,
+        Self::XYZWing,
+        Self::WXYZWing,
+        Self::WWing,
+        Self::VWXYZWing,
+        Self::UVWXYZWing,
+        Self::XYChain,
+        Self::RemotePairs,
+        Self::UniqueRectangle,
+        Self::AlmostUniqueRectangle,
+        Self::Fireworks,
+        Self::ExtendedUniqueRectangle,
+        Self::HiddenUniqueRectangle,
+        Self::EmptyRectangle,
+        Self::SueDeCoq,
+        Self::MultiColoring,
+        Self::Medusa3D,
+        Self::XCycle,
+        Self::AlternatingInferenceChain,
+        Self::AlsXZ,
+        Self::AlsXYWing,
+        Self::DeathBlossom,
+        Self::PatternOverlay,
+        Self::Nishio,
+        Self::CellForcingChain,
+        Self::UnitForcingChain,
+        Self::DigitForcingChain,
+        Self::JuniorExocet,
+        Self::BruteForce,
+    ];
+
     pub const fn difficulty(&self) -> Difficulty {
         match self {
             Self::Given => Difficulty::Trivial,
@@ -137,6 +264,8 @@ impl Strategy {
             Self::XYZWing => Difficulty::Tough,
             Self::AvoidableRectangle => Difficulty::Tough,
             Self::Bug => Difficulty::Tough,
+            Self::WWing => Difficulty::Tough,
+            Self::RemotePairs => Difficulty::Tough,
 
             Self::Jellyfish => Difficulty::Diabolical,
             Self::Skyscraper => Difficulty::Diabolical,
@@ -147,11 +276,62 @@ impl Strategy {
             Self::ExtendedUniqueRectangle => Difficulty::Diabolical,
             Self::HiddenUniqueRectangle => Difficulty::Diabolical,
             Self::WXYZWing => Difficulty::Diabolical,
+            Self::FinnedXWing => Difficulty::Diabolical,
+            Self::SashimiXWing => Difficulty::Diabolical,
+            Self::FinnedSwordfish => Difficulty::Diabolical,
+            Self::SashimiSwordfish => Difficulty::Diabolical,
+            Self::MultiColoring => Difficulty::Diabolical,
+            Self::Medusa3D => Difficulty::Diabolical,
+            Self::SueDeCoq => Difficulty::Diabolical,
+            Self::VWXYZWing => Difficulty::Diabolical,
 
-            Self::BruteForce => Difficulty::Extreme,
+            Self::FinnedJellyfish => Difficulty::Extreme,
+            Self::SashimiJellyfish => Difficulty::Extreme,
+            Self::FrankenFish => Difficulty::Extreme,
+            Self::MutantFish => Difficulty::Extreme,
+            Self::XCycle => Difficulty::Extreme,
+            Self::AlternatingInferenceChain => Difficulty::Extreme,
+            Self::AlsXZ => Difficulty::Extreme,
+            Self::AlsXYWing => Difficulty::Extreme,
+            Self::DeathBlossom => Difficulty::Extreme,
+            Self::PatternOverlay => Difficulty::Extreme,
+            Self::Nishio => Difficulty::Extreme,
+            Self::CellForcingChain => Difficulty::Extreme,
+            Self::UnitForcingChain => Difficulty::Extreme,
+            Self::DigitForcingChain => Difficulty::Extreme,
+            Self::UVWXYZWing => Difficulty::Extreme,
+
+            Self::JuniorExocet => Difficulty::Exotic,
+
+            Self::BruteForce => Difficulty::Exotic,
         }
     }
 
+    /// Returns true if the strategy is only valid for puzzles with a single solution.
+    pub const fn assumes_unique_solution(&self) -> bool {
+        matches!(
+            self,
+            Self::Bug
+                | Self::AvoidableRectangle
+                | Self::UniqueRectangle
+                | Self::AlmostUniqueRectangle
+                | Self::ExtendedUniqueRectangle
+                | Self::HiddenUniqueRectangle
+        )
+    }
+
+    /// Returns true if the strategy assumes a candidate is true or false
+    /// and follows the consequences, much like brute force.
+    pub const fn is_forcing(&self) -> bool {
+        matches!(
+            self,
+            Self::Nishio
+                | Self::CellForcingChain
+                | Self::UnitForcingChain
+                | Self::DigitForcingChain
+        )
+    }
+
     pub const fn label(&self) -> &'static str {
         match self {
             Self::Given => "Given",
@@ -173,6 +353,14 @@ impl Strategy {
             Self::XWing => "X-Wing",
             Self::Swordfish => "Swordfish",
             Self::Jellyfish => "Jellyfish",
+            Self::FinnedXWing => "Finned X-Wing",
+            Self::SashimiXWing => "Sashimi X-Wing",
+            Self::FinnedSwordfish => "Finned Swordfish",
+            Self::SashimiSwordfish => "Sashimi Swordfish",
+            Self::FinnedJellyfish => "Finned Jellyfish",
+            Self::SashimiJellyfish => "Sashimi Jellyfish",
+            Self::FrankenFish => "Franken Fish",
+            Self::MutantFish => "Mutant Fish",
             Self::Bug => "BUG",
             Self::AvoidableRectangle => "Avoidable Rectangle",
             Self::TwoStringKite => "Two-String Kite",
@@ -181,13 +369,31 @@ impl Strategy {
             Self::YWing => "Y-Wing",
             Self::XYZWing => "XYZ-Wing",
             Self::WXYZWing => "WXYZ-Wing",
+            Self::WWing => "W-Wing",
+            Self::VWXYZWing => "VWXYZ-Wing",
+            Self::UVWXYZWing => "UVWXYZ-Wing",
             Self::XYChain => "XY-Chain",
+            Self::RemotePairs => "Remote Pairs",
             Self::UniqueRectangle => "Unique Rectangle",
             Self::AlmostUniqueRectangle => "Almost Unique Rectangle",
             Self::Fireworks => "Fireworks",
             Self::ExtendedUniqueRectangle => "Extended Unique Rectangle",
             Self::HiddenUniqueRectangle => "Hidden Unique Rectangle",
             Self::EmptyRectangle => "Empty Rectangle",
+            Self::SueDeCoq => "Sue de Coq",
+            Self::MultiColoring => "Multi-Coloring",
+            Self::Medusa3D => "3D Medusa",
+            Self::XCycle => "X-Cycle",
+            Self::AlternatingInferenceChain => "Alternating Inference Chain",
+            Self::AlsXZ => "ALS-XZ",
+            Self::AlsXYWing => "ALS-XY-Wing",
+            Self::DeathBlossom => "Death Blossom",
+            Self::PatternOverlay => "Pattern Overlay",
+            Self::Nishio => "Nishio",
+            Self::CellForcingChain => "Cell Forcing Chain",
+            Self::UnitForcingChain => "Unit Forcing Chain",
+            Self::DigitForcingChain => "Digit Forcing Chain",
+            Self::JuniorExocet => "Junior Exocet",
             Self::BruteForce => "Brute Force",
         }
     }
@@ -199,12 +405,93 @@ impl fmt::Display for Strategy {
     }
 }
 
+/// Parses a strategy from its label or name, ignoring case, spaces and punctuation,
+/// so "X-Wing", "xwing" and "XWing" are all accepted.
+impl FromStr for Strategy {
+    type Err = String;
+
+    fn from_str(label: &str) -> Result<Self, Self::Err> {
+        let wanted = normalize(label);
+        Self::ALL
+            .into_iter()
+            .find(|strategy| {
+                normalize(strategy.label()) == wanted
+                    || normalize(&format!("{:?}", strategy)) == wanted
+            })
+            .ok_or_else(|| format!("Unknown strategy \"{}\"", label))
+    }
+}
+
 /// Groups solvers by difficulty based on the SudokuWiki website.
-#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum Difficulty {
     Trivial,
     Basic,
     Tough,
     Diabolical,
     Extreme,
+    /// Patterns such as Exocets found only in the hardest published puzzles,
+    /// and guessing with brute force when no strategy applies.
+    Exotic,
+}
+
+impl Difficulty {
+    /// Every difficulty from easiest to hardest.
+    pub const ALL: [Difficulty; 6] = [
+        Self::Trivial,
+        Self::Basic,
+        Self::Tough,
+        Self::Diabolical,
+        Self::Extreme,
+        Self::Exotic,
+    ];
+}
+
+impl FromStr for Difficulty {
+    type Err = String;
+
+    fn from_str(label: &str) -> Result<Self, Self::Err> {
+        let wanted = normalize(label);
+        Self::ALL
+            .into_iter()
+            .find(|difficulty| normalize(&format!("{:?}", difficulty)) == wanted)
+            .ok_or_else(|| format!("Unknown difficulty \"{}\"", label))
+    }
+}
+
+fn normalize(label: &str) -> String {
+    label
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_strategies_from_labels_and_names() {
+        assert_eq!(Ok(Strategy::XWing), "X-Wing".parse());
+        assert_eq!(Ok(Strategy::XWing), "xwing".parse());
+        assert_eq!(Ok(Strategy::BoxLineReduction), "Box/Line Reduction".parse());
+        assert_eq!(Ok(Strategy::Bug), "bug".parse());
+        assert!("Wing".parse::<Strategy>().is_err());
+    }
+
+    #[test]
+    fn lists_every_strategy() {
+        for strategy in Strategy::ALL {
+            assert_eq!(Ok(strategy), strategy.label().parse());
+        }
+    }
+
+    #[test]
+    fn parses_difficulties() {
+        assert_eq!(Ok(Difficulty::Diabolical), "diabolical".parse());
+        assert_eq!(Ok(Difficulty::Extreme), "Extreme".parse());
+        assert_eq!(Ok(Difficulty::Exotic), "exotic".parse());
+        assert!("impossible".parse::<Difficulty>().is_err());
+    }
 }
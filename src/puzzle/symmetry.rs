@@ -0,0 +1,389 @@
+use std::fmt;
+use std::str::FromStr;
+
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+use crate::layout::{Cell, Known, KnownSet};
+
+use super::{Board, Effects};
+
+const IDENTITY: [u8; 9] = [0, 1, 2, 3, 4, 5, 6, 7, 8];
+const REVERSED: [u8; 9] = [8, 7, 6, 5, 4, 3, 2, 1, 0];
+
+/// Moves the cells and relabels the digits of a board without changing
+/// which cells see each other, producing an essentially identical puzzle.
+///
+/// The grid is first transposed if requested, and then each row and column
+/// is moved to its new position, keeping rows within their bands
+/// and columns within their stacks. Rotations and reflections
+/// are combinations of these.
+///
+/// It is written as `[T/]rows/columns/digits` where each part lists
+/// the new position or digit for 1 through 9, for example
+/// `T/123456789/987654321/123456789` for a quarter turn clockwise.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Symmetry {
+    transpose: bool,
+    rows: [u8; 9],
+    columns: [u8; 9],
+    digits: [u8; 9],
+}
+
+impl Symmetry {
+    /// Returns the symmetry that leaves every board unchanged.
+    pub const fn identity() -> Self {
+        Self {
+            transpose: false,
+            rows: IDENTITY,
+            columns: IDENTITY,
+            digits: IDENTITY,
+        }
+    }
+
+    /// Returns a symmetry from each row's, column's and digit's new position,
+    /// or an error if they are not permutations or split a band or stack.
+    pub fn new(
+        transpose: bool,
+        rows: [u8; 9],
+        columns: [u8; 9],
+        digits: [u8; 9],
+    ) -> Result<Self, String> {
+        if !is_permutation(&rows) || !keeps_bands(&rows) {
+            return Err("Rows must stay within their bands".to_string());
+        }
+        if !is_permutation(&columns) || !keeps_bands(&columns) {
+            return Err("Columns must stay within their stacks".to_string());
+        }
+        if !is_permutation(&digits) {
+            return Err("Digits must be relabeled one-to-one".to_string());
+        }
+        Ok(Self {
+            transpose,
+            rows,
+            columns,
+            digits,
+        })
+    }
+
+    /// Returns a random symmetry from the full group.
+    pub fn random(rng: &mut impl Rng) -> Self {
+        Self {
+            transpose: rng.gen(),
+            rows: shuffle_bands(rng),
+            columns: shuffle_bands(rng),
+            digits: {
+                let mut digits = IDENTITY;
+                digits.shuffle(rng);
+                digits
+            },
+        }
+    }
+
+    /// Swaps the rows and columns.
+    pub const fn transpose() -> Self {
+        Self {
+            transpose: true,
+            ..Self::identity()
+        }
+    }
+
+    /// Rotates the grid clockwise by the given number of quarter turns.
+    pub const fn rotate(quarter_turns: usize) -> Self {
+        match quarter_turns % 4 {
+            0 => Self::identity(),
+            1 => Self {
+                transpose: true,
+                columns: REVERSED,
+                ..Self::identity()
+            },
+            2 => Self {
+                rows: REVERSED,
+                columns: REVERSED,
+                ..Self::identity()
+            },
+            _ => Self {
+                transpose: true,
+                rows: REVERSED,
+                ..Self::identity()
+            },
+        }
+    }
+
+    /// Mirrors the grid left to right.
+    pub const fn reflect_horizontally() -> Self {
+        Self {
+            columns: REVERSED,
+            ..Self::identity()
+        }
+    }
+
+    /// Mirrors the grid top to bottom.
+    pub const fn reflect_vertically() -> Self {
+        Self {
+            rows: REVERSED,
+            ..Self::identity()
+        }
+    }
+
+    /// Returns the symmetry that applies this one followed by the other.
+    pub fn then(&self, other: &Symmetry) -> Self {
+        // transposing after moving rows and columns moves them to the other axis
+        let (rows, columns) = if other.transpose {
+            (self.columns, self.rows)
+        } else {
+            (self.rows, self.columns)
+        };
+        Self {
+            transpose: self.transpose != other.transpose,
+            rows: rows.map(|row| other.rows[row as usize]),
+            columns: columns.map(|column| other.columns[column as usize]),
+            digits: self.digits.map(|digit| other.digits[digit as usize]),
+        }
+    }
+
+    /// Returns the symmetry that undoes this one.
+    pub fn inverse(&self) -> Self {
+        let (rows, columns) = (invert(&self.rows), invert(&self.columns));
+        let (rows, columns) = if self.transpose {
+            (columns, rows)
+        } else {
+            (rows, columns)
+        };
+        Self {
+            transpose: self.transpose,
+            rows,
+            columns,
+            digits: invert(&self.digits),
+        }
+    }
+
+    /// Returns the cell that the given cell moves to.
+    pub fn cell(&self, cell: Cell) -> Cell {
+        let (mut row, mut column) = (cell.usize() / 9, cell.usize() % 9);
+        if self.transpose {
+            (row, column) = (column, row);
+        }
+        Cell::new(9 * self.rows[row] + self.columns[column])
+    }
+
+    /// Returns the digit that the given digit is relabeled to.
+    pub fn known(&self, known: Known) -> Known {
+        Known::from_index(self.digits[known.usize()] as u32)
+    }
+
+    /// Returns a new board with every given, solved cell and candidate moved and relabeled.
+    pub fn apply(&self, board: &Board) -> Board {
+        let mut moved = Board::new();
+        let mut effects = Effects::new();
+
+        for (cell, known) in board.known_iter() {
+            if board.is_given(cell) {
+                moved.set_given(self.cell(cell), self.known(known), &mut effects);
+            } else {
+                moved.set_known(self.cell(cell), self.known(known), &mut effects);
+            }
+        }
+        for (cell, candidates) in board.unknown_iter() {
+            let cell = self.cell(cell);
+            let kept = candidates
+                .iter()
+                .map(|known| self.known(known))
+                .collect::<KnownSet>();
+            moved.remove_candidates(cell, moved.candidates(cell) - kept, &mut effects);
+        }
+
+        moved
+    }
+}
+
+impl Default for Symmetry {
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl fmt::Display for Symmetry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let labels = |positions: &[u8; 9]| {
+            positions
+                .iter()
+                .map(|position| (b'1' + position) as char)
+                .collect::<String>()
+        };
+        if self.transpose {
+            f.write_str("T/")?;
+        }
+        write!(
+            f,
+            "{}/{}/{}",
+            labels(&self.rows),
+            labels(&self.columns),
+            labels(&self.digits)
+        )
+    }
+}
+
+impl FromStr for Symmetry {
+    type Err = String;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let mut parts = input.trim().split('/').collect::<Vec<_>>();
+        let transpose = parts
+            .first()
+            .is_some_and(|part| part.eq_ignore_ascii_case("t"));
+        if transpose {
+            parts.remove(0);
+        }
+        if parts.len() != 3 {
+            return Err(format!("Invalid symmetry \"{}\"", input));
+        }
+
+        let mut positions = [IDENTITY; 3];
+        for (part, positions) in parts.iter().zip(positions.iter_mut()) {
+            let digits = part
+                .chars()
+                .map(|c| Known::try_from(c).map(|known| known.usize() as u8))
+                .collect::<Result<Vec<_>, _>>()?;
+            *positions = digits
+                .try_into()
+                .map_err(|_| format!("Invalid symmetry \"{}\"", input))?;
+        }
+
+        let [rows, columns, digits] = positions;
+        Symmetry::new(transpose, rows, columns, digits)
+    }
+}
+
+fn is_permutation(positions: &[u8; 9]) -> bool {
+    let mut seen = 0u16;
+    for position in positions {
+        if *position > 8 {
+            return false;
+        }
+        seen |= 1 << position;
+    }
+    seen == 0x1FF
+}
+
+fn keeps_bands(positions: &[u8; 9]) -> bool {
+    positions
+        .chunks(3)
+        .all(|band| band.iter().all(|position| position / 3 == band[0] / 3))
+}
+
+fn invert(positions: &[u8; 9]) -> [u8; 9] {
+    let mut inverted = IDENTITY;
+    for (i, position) in positions.iter().enumerate() {
+        inverted[*position as usize] = i as u8;
+    }
+    inverted
+}
+
+fn shuffle_bands(rng: &mut impl Rng) -> [u8; 9] {
+    let mut bands = [0, 1, 2];
+    bands.shuffle(rng);
+
+    let mut positions = [0; 9];
+    for (band, moved) in bands.into_iter().enumerate() {
+        let mut within = [0, 1, 2];
+        within.shuffle(rng);
+        for (i, offset) in within.into_iter().enumerate() {
+            positions[3 * band + i] = 3 * moved + offset;
+        }
+    }
+    positions
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha8Rng;
+
+    use crate::io::{Parse, Parser};
+    use crate::layout::cells::cell::cell;
+    use crate::layout::values::known::known;
+    use crate::puzzle::canonical_board;
+
+    use super::*;
+
+    const PUZZLE: &str =
+        "..2...376.1..3.5.........9.9..85...1...3.4...2...97..3.8.........3.4..6.147...2..";
+
+    #[test]
+    fn rotates_and_reflects_cells() {
+        assert_eq!(cell!("A9"), Symmetry::rotate(1).cell(cell!("A1")));
+        assert_eq!(cell!("B7"), Symmetry::rotate(1).cell(cell!("C2")));
+        assert_eq!(cell!("H8"), Symmetry::rotate(2).cell(cell!("B2")));
+        assert_eq!(cell!("H2"), Symmetry::rotate(3).cell(cell!("B2")));
+        assert_eq!(
+            cell!("A8"),
+            Symmetry::reflect_horizontally().cell(cell!("A2"))
+        );
+        assert_eq!(
+            cell!("H1"),
+            Symmetry::reflect_vertically().cell(cell!("B1"))
+        );
+        assert_eq!(
+            Symmetry::rotate(2),
+            Symmetry::rotate(1).then(&Symmetry::rotate(1))
+        );
+        assert_eq!(
+            Symmetry::identity(),
+            Symmetry::rotate(3).then(&Symmetry::rotate(1))
+        );
+    }
+
+    #[test]
+    fn inverse_restores_the_board() {
+        let mut rng = ChaCha8Rng::seed_from_u64(42);
+        let board = Parse::packed().parse_simple(PUZZLE);
+
+        for _ in 0..20 {
+            let symmetry = Symmetry::random(&mut rng);
+            let moved = symmetry.apply(&board);
+
+            assert_eq!(board, symmetry.inverse().apply(&moved));
+            assert_eq!(Symmetry::identity(), symmetry.then(&symmetry.inverse()));
+            assert_eq!(canonical_board(&board), canonical_board(&moved));
+        }
+    }
+
+    #[test]
+    fn preserves_givens_solved_cells_and_candidates() {
+        let mut board = Parse::packed().parse_simple(PUZZLE);
+        let mut effects = Effects::new();
+        board.set_known(cell!("A1"), known!("5"), &mut effects);
+        board.remove_candidate(cell!("A2"), known!("4"), &mut effects);
+
+        let symmetry: Symmetry = "T/213456789/123456798/234567891".parse().unwrap();
+        let moved = symmetry.apply(&board);
+
+        assert!(moved.is_given(cell!("C1")));
+        assert!(moved.is_known(cell!("B1")) && !moved.is_given(cell!("B1")));
+        assert_eq!(known!("6").value(), moved.value(cell!("B1")));
+        assert!(!moved.candidates(cell!("A1")).has(known!("5")));
+        for cell in Cell::iter() {
+            let candidates = board
+                .candidates(cell)
+                .iter()
+                .map(|known| symmetry.known(known))
+                .collect::<KnownSet>();
+            assert_eq!(candidates, moved.candidates(symmetry.cell(cell)));
+        }
+    }
+
+    #[test]
+    fn parses_and_formats() {
+        let symmetry = Symmetry::random(&mut ChaCha8Rng::seed_from_u64(7));
+        assert_eq!(Ok(symmetry), symmetry.to_string().parse());
+        assert_eq!(
+            "T/123456789/987654321/123456789",
+            Symmetry::rotate(1).to_string()
+        );
+
+        assert!("123456789/123456789".parse::<Symmetry>().is_err());
+        assert!("412356789/123456789/123456789".parse::<Symmetry>().is_err());
+        assert!("123456789/123456789/113456789".parse::<Symmetry>().is_err());
+    }
+}
@@ -1,14 +1,23 @@
 //! Provides various strategies for validating and solving Sudoku puzzles.
 
-pub use algorithms::{find_brute_force, find_intersection_removals, BruteForceResult};
+pub use algorithms::{
+    count_solutions, find_brute_force, find_brute_force_with_changer, find_intersection_removals,
+    find_template_contradiction, BruteForceResult, SolutionCount,
+};
 pub use deadly_rectangles::creates_deadly_rectangles;
+pub use profile::{exclude_techniques, select_techniques, Profiles};
+pub use rating::{chain_length_penalty, rate_action, rate_strategy, Rating};
 pub use reporter::Reporter;
 pub use solver::{Resolution, Solver};
-pub use technique::{NON_PEER_TECHNIQUES, TECHNIQUES};
+pub use technique::{
+    find_technique, Technique, MANUAL_TECHNIQUES, NON_PEER_TECHNIQUES, TECHNIQUES,
+};
 pub use timing::Timings;
 
 pub mod algorithms;
 mod deadly_rectangles;
+mod profile;
+mod rating;
 mod reporter;
 mod solver;
 mod technique;
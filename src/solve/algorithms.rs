@@ -1,56 +1,82 @@
 use itertools::Itertools;
 
+pub use almost_locked_sets::{find_als_xy_wings, find_als_xz, find_death_blossoms};
 pub use avoidable_rectangles::find_avoidable_rectangles;
-pub use brute_force::{find_brute_force, BruteForceResult};
+pub use brute_force::{
+    count_solutions, find_brute_force, find_brute_force_with_changer, BruteForceResult,
+    SolutionCount,
+};
 pub use bugs::find_bugs;
 pub use empty_rectangles::find_empty_rectangles;
+pub use exocets::find_junior_exocets;
 pub use extended_unique_rectangles::find_extended_unique_rectangles;
 pub use fireworks::find_fireworks;
 pub use fish::find_jellyfish;
 pub use fish::find_swordfish;
 pub use fish::find_x_wings;
+pub use fish::{find_finned_jellyfish, find_finned_swordfish, find_finned_x_wings};
+pub use fish::{find_franken_fish, find_mutant_fish};
+pub use fish::{find_sashimi_jellyfish, find_sashimi_swordfish, find_sashimi_x_wings};
+pub use forcing_chains::{find_cell_forcing_chains, find_digit_forcing_chains};
+pub use forcing_chains::{find_nishios, find_unit_forcing_chains};
 pub use hidden_singles::find_hidden_singles;
 pub use hidden_tuples::find_hidden_pairs;
 pub use hidden_tuples::find_hidden_quads;
 pub use hidden_tuples::find_hidden_triples;
 pub use hidden_unique_rectangles::find_hidden_unique_rectangles;
+pub use inference_chains::{find_alternating_inference_chains, find_x_cycles};
 pub use intersection_removals::find_intersection_removals;
+pub use medusa::find_medusas;
+pub use multi_colorings::find_multi_colorings;
 pub use naked_singles::find_naked_singles;
 pub use naked_tuples::find_naked_pairs;
 pub use naked_tuples::find_naked_quads;
 pub use naked_tuples::find_naked_triples;
+pub use pattern_overlays::{find_pattern_overlays, find_template_contradiction, templates};
 pub use peers::find_peers;
+pub use remote_pairs::find_remote_pairs;
 pub use singles_chains::find_singles_chains;
 pub use skyscrapers::find_skyscrapers;
+pub use sue_de_coqs::find_sue_de_coqs;
 pub use two_string_kites::find_two_string_kites;
 pub use unique_rectangles::{find_almost_unique_rectangles, find_unique_rectangles};
-pub use wxyz_wings::find_wxyz_wings;
+pub use w_wings::find_w_wings;
+pub use wings::{
+    find_uvwxyz_wings, find_vwxyz_wings, find_wxyz_wings, find_xyz_wings, find_y_wings,
+};
 pub use xy_chains::find_xy_chains;
-pub use xyz_wings::find_xyz_wings;
-pub use y_wings::find_y_wings;
 
 use crate::layout::*;
 use crate::puzzle::*;
 
+mod almost_locked_sets;
 mod avoidable_rectangles;
+mod backtracking;
 mod brute_force;
 mod bugs;
 mod empty_rectangles;
+mod exocets;
 mod extended_unique_rectangles;
 mod fireworks;
 mod fish;
+mod forcing_chains;
 mod hidden_singles;
 mod hidden_tuples;
 mod hidden_unique_rectangles;
+mod inference_chains;
 mod intersection_removals;
+mod medusa;
+mod multi_colorings;
 mod naked_singles;
 mod naked_tuples;
+mod pattern_overlays;
 mod peers;
+mod remote_pairs;
 mod singles_chains;
 mod skyscrapers;
+mod sue_de_coqs;
 mod two_string_kites;
 mod unique_rectangles;
-mod wxyz_wings;
+mod w_wings;
+mod wings;
 mod xy_chains;
-mod xyz_wings;
-mod y_wings;
@@ -0,0 +1,366 @@
+use std::collections::HashSet;
+
+use super::*;
+
+/// Two almost locked sets joined by one or two restricted common candidates.
+pub fn find_als_xz(board: &Board, single: bool) -> Option<Effects> {
+    let mut effects = Effects::new();
+
+    let sets = find_almost_locked_sets(board);
+    for (i, a) in sets.iter().enumerate() {
+        for b in &sets[i + 1..] {
+            let restricted = restricted_commons(board, a, b);
+            if restricted.is_empty() {
+                continue;
+            }
+
+            let mut action = Action::new(Strategy::AlsXZ);
+            if let Some(x) = restricted.as_single() {
+                // one set must hold x and lock the other, so every other common
+                // candidate must appear in one of them
+                for z in (a.knowns & b.knowns) - x {
+                    let cells = a.cells_with(board, z) | b.cells_with(board, z);
+                    action.erase_cells(cells.peers() & board.candidate_cells(z), z);
+                }
+            } else {
+                // each set holds one of the restricted commons, locking both
+                for x in restricted {
+                    let cells = a.cells_with(board, x) | b.cells_with(board, x);
+                    action.erase_cells(cells.peers() & board.candidate_cells(x), x);
+                }
+                for set in [a, b] {
+                    for k in set.knowns - restricted {
+                        let cells = set.cells_with(board, k);
+                        action.erase_cells(cells.peers() & board.candidate_cells(k), k);
+                    }
+                }
+            }
+            if action.is_empty() {
+                continue;
+            }
+
+            a.clue(board, &mut action, Verdict::Primary, restricted);
+            b.clue(board, &mut action, Verdict::Secondary, restricted);
+            clue_restricted(board, &mut action, a.cells | b.cells, restricted);
+
+            if effects.add_action(action) && single {
+                return Some(effects);
+            }
+        }
+    }
+
+    if effects.has_actions() {
+        Some(effects)
+    } else {
+        None
+    }
+}
+
+/// Two almost locked sets that are each joined to a third by a different
+/// restricted common candidate.
+pub fn find_als_xy_wings(board: &Board, single: bool) -> Option<Effects> {
+    let mut effects = Effects::new();
+
+    let sets = find_almost_locked_sets(board);
+    let links = link_restricted_commons(board, &sets);
+
+    for (pivot, pivot_links) in links.iter().enumerate() {
+        let c = &sets[pivot];
+        for ((i, rx), (j, ry)) in pivot_links.iter().tuple_combinations() {
+            let (a, b) = (&sets[*i], &sets[*j]);
+            if a.cells.has_any(b.cells) {
+                continue;
+            }
+
+            for x in *rx {
+                for y in *ry - x {
+                    // either a holds x or b holds y, so one of them must hold z
+                    let mut action = Action::new(Strategy::AlsXYWing);
+                    for z in (a.knowns & b.knowns) - x - y {
+                        let cells = a.cells_with(board, z) | b.cells_with(board, z);
+                        action.erase_cells(cells.peers() & board.candidate_cells(z), z);
+                    }
+                    if action.is_empty() {
+                        continue;
+                    }
+
+                    let (x, y) = (KnownSet::empty() + x, KnownSet::empty() + y);
+                    a.clue(board, &mut action, Verdict::Primary, x);
+                    b.clue(board, &mut action, Verdict::Primary, y);
+                    c.clue(board, &mut action, Verdict::Secondary, x | y);
+                    clue_restricted(board, &mut action, a.cells | c.cells, x);
+                    clue_restricted(board, &mut action, b.cells | c.cells, y);
+
+                    if effects.add_action(action) && single {
+                        return Some(effects);
+                    }
+                }
+            }
+        }
+    }
+
+    if effects.has_actions() {
+        Some(effects)
+    } else {
+        None
+    }
+}
+
+/// A stem cell whose every candidate is seen by a separate almost locked set,
+/// all of which share another candidate.
+pub fn find_death_blossoms(board: &Board, single: bool) -> Option<Effects> {
+    let mut effects = Effects::new();
+
+    let sets = find_almost_locked_sets(board);
+
+    for stem in board.cells_with_n_candidates(2) | board.cells_with_n_candidates(3) {
+        let stem_knowns = board.candidates(stem);
+
+        for z in KnownSet::full() - stem_knowns {
+            // the petals for each stem candidate, all holding z
+            let petals = stem_knowns
+                .iter()
+                .map(|k| {
+                    sets.iter()
+                        .filter(|set| {
+                            !set.cells.has(stem)
+                                && set.knowns.has(z)
+                                && set.knowns.has(k)
+                                && set.cells_with(board, k).is_subset_of(stem.peers())
+                        })
+                        .collect_vec()
+                })
+                .collect_vec();
+            if petals.iter().any(|petals| petals.is_empty()) {
+                continue;
+            }
+
+            for blossom in petals.into_iter().multi_cartesian_product() {
+                let cells = blossom.iter().map(|set| set.cells).collect_vec();
+                if cells.iter().map(|cells| cells.len()).sum::<usize>()
+                    != cells.iter().copied().union_cells().len()
+                {
+                    // overlapping petals
+                    continue;
+                }
+
+                // whichever candidate the stem takes locks its petal, leaving z
+                let z_cells = blossom
+                    .iter()
+                    .map(|set| set.cells_with(board, z))
+                    .union_cells();
+                let mut action = Action::new(Strategy::DeathBlossom);
+                action.erase_cells(z_cells.peers() & board.candidate_cells(z), z);
+                if action.is_empty() {
+                    continue;
+                }
+
+                action.clue_cell_for_knowns(Verdict::Secondary, stem, stem_knowns);
+                for (set, k) in blossom.iter().zip(stem_knowns.iter()) {
+                    set.clue(board, &mut action, Verdict::Primary, KnownSet::empty() + k);
+                    action.clue_cells_for_known(Verdict::Secondary, set.cells_with(board, k), k);
+                }
+
+                if effects.add_action(action) && single {
+                    return Some(effects);
+                }
+            }
+        }
+    }
+
+    if effects.has_actions() {
+        Some(effects)
+    } else {
+        None
+    }
+}
+
+/// A set of cells in one house with exactly one more candidate than cells.
+///
+/// Removing any one of its candidates leaves the rest locked in the set.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct AlmostLockedSet {
+    cells: CellSet,
+    knowns: KnownSet,
+}
+
+impl AlmostLockedSet {
+    /// Returns the cells in the set that have the candidate.
+    fn cells_with(&self, board: &Board, known: Known) -> CellSet {
+        self.cells & board.candidate_cells(known)
+    }
+
+    /// Marks every candidate in the set except those clued separately.
+    fn clue(&self, board: &Board, action: &mut Action, verdict: Verdict, except: KnownSet) {
+        for cell in self.cells {
+            action.clue_cell_for_knowns(verdict, cell, board.candidates(cell) - except);
+        }
+    }
+}
+
+/// Returns every almost locked set in each row, column and box,
+/// including the bi-value cells.
+fn find_almost_locked_sets(board: &Board) -> Vec<AlmostLockedSet> {
+    let mut seen = HashSet::new();
+    let mut sets = Vec::new();
+
+    for house in House::iter() {
+        let unknowns = house.cells() & board.unknowns();
+        for size in 1..unknowns.len() {
+            for cells in unknowns.iter().combinations(size) {
+                let cells = cells.into_iter().union_cells();
+                let knowns = board.all_candidates(cells);
+                if knowns.len() == size + 1 && seen.insert(cells) {
+                    sets.push(AlmostLockedSet { cells, knowns });
+                }
+            }
+        }
+    }
+
+    sets
+}
+
+/// Returns the candidates common to both sets where every cell with one
+/// in either set sees every cell with it in the other, so at most one set
+/// may hold it.
+fn restricted_commons(board: &Board, a: &AlmostLockedSet, b: &AlmostLockedSet) -> KnownSet {
+    if a.cells.has_any(b.cells) {
+        return KnownSet::empty();
+    }
+
+    (a.knowns & b.knowns)
+        .iter()
+        .filter(|known| {
+            let b_cells = b.cells_with(board, *known);
+            a.cells_with(board, *known)
+                .iter()
+                .all(|cell| b_cells.is_subset_of(cell.peers()))
+        })
+        .union()
+}
+
+/// Returns the other sets joined to each set by their restricted commons.
+fn link_restricted_commons(board: &Board, sets: &[AlmostLockedSet]) -> Vec<Vec<(usize, KnownSet)>> {
+    let mut links = vec![Vec::new(); sets.len()];
+
+    for (i, a) in sets.iter().enumerate() {
+        for (j, b) in sets.iter().enumerate().skip(i + 1) {
+            let restricted = restricted_commons(board, a, b);
+            if !restricted.is_empty() {
+                links[i].push((j, restricted));
+                links[j].push((i, restricted));
+            }
+        }
+    }
+
+    links
+}
+
+fn clue_restricted(board: &Board, action: &mut Action, cells: CellSet, restricted: KnownSet) {
+    for known in restricted {
+        action.clue_cells_for_known(
+            Verdict::Tertiary,
+            cells & board.candidate_cells(known),
+            known,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::io::{Parse, Parser};
+    use crate::layout::cells::cell::cell;
+    use crate::layout::cells::cell_set::cells;
+    use crate::layout::values::known::known;
+    use crate::layout::values::known_set::knowns;
+
+    use super::*;
+
+    #[test]
+    fn als_xz() {
+        let parser = Parse::wiki().stop_on_error();
+        let (board, ..) = parser.parse(
+            "81i0046209m012j20h41110o2m8iq486ieaeigi803118gq48441acggga9o0u419c211m962g629gg18i94091md6054a9o0q2198di1ig11a80g16804681i3q3a0a044028100hg0812a180h2180g103541c5c",
+        );
+
+        if let Some(got) = find_als_xz(&board, true) {
+            let mut action = Action::new(Strategy::AlsXZ);
+            action.erase(cell!("G9"), known!("3"));
+            action.clue_cells_for_knowns(Verdict::Primary, cells!("G4 G6"), knowns!("3 7"));
+            action.clue_cells_for_knowns(Verdict::Secondary, cells!("D8 E8"), knowns!("1 2 4 5"));
+            action.clue_cell_for_knowns(Verdict::Secondary, cell!("F8"), knowns!("1 4 5"));
+            action.clue_cell_for_knowns(Verdict::Secondary, cell!("G8"), knowns!("1 3 4 5"));
+            action.clue_cell_for_knowns(Verdict::Secondary, cell!("J8"), knowns!("2 3 5"));
+            action.clue_cells_for_known(Verdict::Tertiary, cells!("G4 G6 G8"), known!("6"));
+
+            assert_eq!(format!("{:?}", action), format!("{:?}", got.actions()[0]));
+        } else {
+            panic!("not found");
+        }
+    }
+
+    #[test]
+    fn als_xy_wing() {
+        let parser = Parse::wiki().stop_on_error();
+        let (board, ..) = parser.parse(
+            "050hh2g29021o8kakam209i2050gc2q011g2n281j2h20952i00k0kh2l6g60g2009h0k481r8j0q841059003iggob8740h92g192186c4cp8h4pc20030g41o8h80g32b20940g1058212gag2419090040hga21",
+        );
+
+        if let Some(got) = find_als_xy_wings(&board, true) {
+            let action = &got.actions()[0];
+            assert_eq!(
+                vec![(cell!("E3"), knowns!("9"))],
+                action.collect_erases().collect_vec()
+            );
+            assert!(action.has_clues());
+        } else {
+            panic!("not found");
+        }
+    }
+
+    #[test]
+    fn death_blossom() {
+        let parser = Parse::wiki().stop_on_error();
+        let (board, ..) = parser.parse(
+            "ik2g031141og09ikg4815olk2103golghkl4ns7olkgsg4go81jml65kg15k03086g5081545ododgsgo005l2ha2103c821s0o011k0gc0hngfi09s0p0s205jip2j005p0o80hoaj241palgditgsc21sahihqpa",
+        );
+
+        if let Some(got) = find_death_blossoms(&board, true) {
+            let mut action = Action::new(Strategy::DeathBlossom);
+            action.erase(cell!("C8"), known!("2"));
+            action.clue_cell_for_knowns(Verdict::Secondary, cell!("E5"), knowns!("8 9"));
+            action.clue_cell_for_knowns(Verdict::Primary, cell!("F4"), knowns!("7 9"));
+            action.clue_cell_for_known(Verdict::Primary, cell!("F5"), known!("9"));
+            action.clue_cell_for_knowns(Verdict::Primary, cell!("F7"), knowns!("7 9"));
+            action.clue_cell_for_knowns(Verdict::Primary, cell!("F8"), knowns!("2 3 9"));
+            action.clue_cells_for_known(Verdict::Secondary, cells!("F4 F5"), known!("8"));
+            action.clue_cell_for_known(Verdict::Primary, cell!("C5"), known!("2"));
+            action.clue_cell_for_known(Verdict::Secondary, cell!("C5"), known!("9"));
+
+            assert_eq!(format!("{:?}", action), format!("{:?}", got.actions()[0]));
+        } else {
+            panic!("not found");
+        }
+    }
+
+    #[test]
+    fn finds_almost_locked_sets_once() {
+        let mut board = Board::new();
+        let mut effects = Effects::new();
+        board.remove_candidates_from_cells(cells!("A1 A2"), knowns!("1 2 3 4 5 6 7"), &mut effects);
+
+        let sets = find_almost_locked_sets(&board);
+
+        assert!(sets.contains(&AlmostLockedSet {
+            cells: cells!("A1"),
+            knowns: knowns!("8 9"),
+        }));
+        assert_eq!(
+            sets.len(),
+            sets.iter()
+                .map(|set| set.cells)
+                .collect::<HashSet<_>>()
+                .len()
+        );
+    }
+}
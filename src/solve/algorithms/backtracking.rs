@@ -0,0 +1,337 @@
+use crate::io::Cancelable;
+
+use super::*;
+
+const ALL_DIGITS: u16 = 0x1FF;
+
+/// The twenty cells that share a row, column or block with each cell.
+const PEERS: [[u8; 20]; 81] = {
+    let mut peers = [[0; 20]; 81];
+    let mut cell = 0;
+    while cell < 81 {
+        let mut count = 0;
+        let mut peer = 0;
+        while peer < 81 {
+            if peer != cell && sees(cell, peer) {
+                peers[cell][count] = peer as u8;
+                count += 1;
+            }
+            peer += 1;
+        }
+        cell += 1;
+    }
+    peers
+};
+
+/// The nine cells in each row, column and block.
+const HOUSES: [[u8; 9]; 27] = {
+    let mut houses = [[0; 9]; 27];
+    let mut i = 0;
+    while i < 9 {
+        let mut j = 0;
+        while j < 9 {
+            houses[i][j] = (9 * i + j) as u8;
+            houses[9 + i][j] = (9 * j + i) as u8;
+            houses[18 + i][j] = (27 * (i / 3) + 3 * (i % 3) + 9 * (j / 3) + j % 3) as u8;
+            j += 1;
+        }
+        i += 1;
+    }
+    houses
+};
+
+const fn sees(first: usize, second: usize) -> bool {
+    let (r1, c1, r2, c2) = (first / 9, first % 9, second / 9, second % 9);
+    r1 == r2 || c1 == c2 || (r1 / 3 == r2 / 3 && c1 / 3 == c2 / 3)
+}
+
+/// Holds each cell's candidates as nine bits and its digit, if solved,
+/// small enough to copy at every guess.
+#[derive(Clone, Copy)]
+struct Grid {
+    candidates: [u16; 81],
+    digits: [u8; 81],
+    unsolved: u8,
+}
+
+impl Grid {
+    /// Copies the board's candidates and solved cells,
+    /// returning `None` if any solved cell contradicts another.
+    fn from_board(board: &Board) -> Option<Grid> {
+        let mut grid = Grid {
+            candidates: [0; 81],
+            digits: [0; 81],
+            unsolved: 81,
+        };
+        for cell in board.unknowns() {
+            grid.candidates[cell.usize()] = board.candidates(cell).bits();
+        }
+        for (cell, known) in board.known_iter() {
+            grid.candidates[cell.usize()] = known.bit();
+            if !grid.place(cell.usize(), known.usize()) {
+                return None;
+            }
+        }
+        Some(grid)
+    }
+
+    /// Solves the cell and removes the digit from its peers,
+    /// returning false if that leaves any peer without a candidate.
+    fn place(&mut self, cell: usize, digit: usize) -> bool {
+        let bit = 1 << digit;
+        if self.digits[cell] != 0 || self.candidates[cell] & bit == 0 {
+            return false;
+        }
+
+        self.digits[cell] = digit as u8 + 1;
+        self.candidates[cell] = 0;
+        self.unsolved -= 1;
+        for peer in PEERS[cell] {
+            let peer = peer as usize;
+            if self.candidates[peer] & bit != 0 {
+                self.candidates[peer] &= !bit;
+                if self.candidates[peer] == 0 {
+                    return false;
+                }
+            } else if self.digits[peer] == digit as u8 + 1 {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Places naked and hidden singles until none remain,
+    /// returning false if the grid has no solution.
+    fn propagate(&mut self) -> bool {
+        loop {
+            let mut changed = false;
+
+            for cell in 0..81 {
+                let candidates = self.candidates[cell];
+                if self.digits[cell] == 0 && candidates.count_ones() == 1 {
+                    if !self.place(cell, candidates.trailing_zeros() as usize) {
+                        return false;
+                    }
+                    changed = true;
+                }
+            }
+
+            for house in HOUSES {
+                let (mut once, mut twice, mut solved) = (0, 0, 0);
+                for cell in house {
+                    let cell = cell as usize;
+                    match self.digits[cell] {
+                        0 => {
+                            let candidates = self.candidates[cell];
+                            twice |= once & candidates;
+                            once |= candidates;
+                        }
+                        digit => solved |= 1 << (digit - 1),
+                    }
+                }
+                if once | solved != ALL_DIGITS {
+                    return false;
+                }
+
+                let mut singles = once & !twice & !solved;
+                while singles != 0 {
+                    let digit = singles.trailing_zeros() as usize;
+                    singles &= singles - 1;
+                    let Some(cell) = house
+                        .iter()
+                        .map(|cell| *cell as usize)
+                        .find(|cell| self.candidates[*cell] & (1 << digit) != 0)
+                    else {
+                        return false;
+                    };
+                    if !self.place(cell, digit) {
+                        return false;
+                    }
+                    changed = true;
+                }
+            }
+
+            if !changed {
+                return true;
+            }
+        }
+    }
+
+    /// Returns the unsolved cell with the fewest candidates.
+    fn fewest_candidates(&self) -> usize {
+        (0..81)
+            .filter(|cell| self.digits[*cell] == 0)
+            .min_by_key(|cell| self.candidates[*cell].count_ones())
+            .unwrap()
+    }
+
+    fn to_board(self, board: &Board) -> Board {
+        let mut solution = *board;
+        let mut effects = Effects::new();
+        for cell in board.unknowns() {
+            let known = Known::new(self.digits[cell.usize()]);
+            solution.set_known(cell, known, &mut effects);
+        }
+        solution
+    }
+}
+
+/// The outcome of a backtracking search.
+pub(super) enum Search {
+    Canceled,
+    Finished(Vec<Board>),
+}
+
+/// Why a search stopped before trying every candidate.
+enum Stop {
+    Canceled,
+    Enough,
+}
+
+/// Finds up to `max_solutions` solutions by guessing the candidates of the cell
+/// with the fewest remaining after placing every naked and hidden single.
+pub(super) fn backtrack(board: &Board, max_solutions: usize) -> Search {
+    let mut found = Vec::new();
+    let result = visit_solutions(board, |grid| {
+        found.push(grid.to_board(board));
+        found.len() < max_solutions
+    });
+
+    match result {
+        Err(Stop::Canceled) => Search::Canceled,
+        _ => Search::Finished(found),
+    }
+}
+
+/// Counts up to `max_solutions` solutions without building a board for each,
+/// passing the digit in every cell of each solution to `visit`.
+pub(super) fn count(
+    board: &Board,
+    max_solutions: usize,
+    mut visit: impl FnMut(&[u8; 81]),
+) -> SolutionCount {
+    let mut count = 0;
+    let result = visit_solutions(board, |grid| {
+        visit(&grid.digits);
+        count += 1;
+        count < max_solutions
+    });
+
+    match result {
+        Ok(()) => SolutionCount::Exact(count),
+        Err(Stop::Enough) => SolutionCount::AtLeast(count),
+        Err(Stop::Canceled) => SolutionCount::Canceled(count),
+    }
+}
+
+/// Passes each solution to `visit` until it returns false.
+fn visit_solutions(board: &Board, mut visit: impl FnMut(&Grid) -> bool) -> Result<(), Stop> {
+    match Grid::from_board(board) {
+        Some(grid) => search(grid, &Cancelable::new(), &mut visit),
+        None => Ok(()),
+    }
+}
+
+fn search(
+    mut grid: Grid,
+    cancelable: &Cancelable,
+    visit: &mut impl FnMut(&Grid) -> bool,
+) -> Result<(), Stop> {
+    if cancelable.is_canceled() {
+        return Err(Stop::Canceled);
+    }
+    if !grid.propagate() {
+        return Ok(());
+    }
+    if grid.unsolved == 0 {
+        return if visit(&grid) {
+            Ok(())
+        } else {
+            Err(Stop::Enough)
+        };
+    }
+
+    let cell = grid.fewest_candidates();
+    let mut candidates = grid.candidates[cell];
+    while candidates != 0 {
+        let digit = candidates.trailing_zeros() as usize;
+        candidates &= candidates - 1;
+
+        let mut next = grid;
+        if next.place(cell, digit) {
+            search(next, cancelable, visit)?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::io::{Parse, Parser};
+
+    use super::*;
+
+    #[test]
+    fn solves_hard_puzzles() {
+        let parser = Parse::packed_with_options(Options::errors());
+        let board = parser.parse_simple(
+            "1.......2.9.4...5...6...7...5.9.3.......7.......85..4.7.....6...3...9.8...2.....1",
+        );
+
+        let Search::Finished(solutions) = backtrack(&board, 2) else {
+            panic!("canceled");
+        };
+        assert_eq!(1, solutions.len());
+        assert!(solutions[0].is_fully_solved());
+        assert!(board
+            .known_iter()
+            .all(|(cell, known)| solutions[0].value(cell) == known.value()));
+    }
+
+    #[test]
+    fn stops_after_enough_solutions() {
+        let parser = Parse::packed_with_options(Options::errors());
+        let board = parser.parse_simple(
+            "........................................................................123456789",
+        );
+
+        let Search::Finished(solutions) = backtrack(&board, 5) else {
+            panic!("canceled");
+        };
+        assert_eq!(5, solutions.len());
+    }
+
+    #[test]
+    fn detects_contradictions() {
+        // row A has no cell for 8
+        let parser = Parse::packed();
+        let board = parser.parse_simple(
+            "1234567...........................8...........................8..................",
+        );
+
+        let Search::Finished(solutions) = backtrack(&board, 2) else {
+            panic!("canceled");
+        };
+        assert!(solutions.is_empty());
+    }
+
+    #[test]
+    fn counts_solutions_without_boards() {
+        let parser = Parse::packed_with_options(Options::errors());
+        let board = parser.parse_simple(
+            "........................................................................123456789",
+        );
+
+        let mut first = None;
+        let found = count(&board, 100, |digits| {
+            first.get_or_insert(*digits);
+        });
+        assert_eq!(SolutionCount::AtLeast(100), found);
+        assert_eq!([1, 2, 3, 4, 5, 6, 7, 8, 9], first.unwrap()[72..]);
+
+        let board = parser.parse_simple(
+            "1.......2.9.4...5...6...7...5.9.3.......7.......85..4.7.....6...3...9.8...2.....1",
+        );
+        assert_eq!(SolutionCount::Exact(1), count(&board, 100, |_| ()));
+    }
+}
@@ -3,6 +3,7 @@ use std::time::Duration;
 
 use crate::io::{print_all_and_single_candidates, Cancelable};
 
+use super::backtracking::{backtrack, count, Search};
 use super::*;
 
 const MINIMUM_KNOWNS_TO_BE_UNIQUELY_SOLVABLE: usize = 17;
@@ -10,27 +11,75 @@ const MINIMUM_KNOWNS_TO_BE_UNIQUELY_SOLVABLE: usize = 17;
 const MAXIMUM_SOLUTIONS: usize = 1_000_000;
 const DEFAULT_MAXIMUM_SOLUTIONS: usize = 1_000;
 
+/// Finds up to `max_solutions` solutions to the board,
+/// logging each guess using the slower [`find_brute_force_with_changer`] when `log` is set.
 pub fn find_brute_force(
     board: &Board,
     log: bool,
     pause: u32,
-    mut max_solutions: usize,
+    max_solutions: usize,
 ) -> BruteForceResult {
+    let max_solutions = match check_board(board, max_solutions) {
+        Ok(max_solutions) => max_solutions,
+        Err(result) => return result,
+    };
+    if log {
+        return find_brute_force_with_changer(board, log, pause, max_solutions);
+    }
+
+    match backtrack(board, max_solutions) {
+        Search::Canceled => BruteForceResult::Canceled,
+        Search::Finished(solutions) if solutions.len() >= max_solutions => {
+            BruteForceResult::MultipleSolutions(solutions)
+        }
+        Search::Finished(solutions) => collect_solutions(solutions),
+    }
+}
+
+/// Returns the maximum number of solutions to find
+/// or the result if the board cannot be searched.
+fn check_board(board: &Board, max_solutions: usize) -> Result<usize, BruteForceResult> {
     if board.is_fully_solved() {
-        return BruteForceResult::AlreadySolved;
+        return Err(BruteForceResult::AlreadySolved);
     }
     if board.known_count() < MINIMUM_KNOWNS_TO_BE_UNIQUELY_SOLVABLE {
-        return BruteForceResult::TooFewKnowns;
+        return Err(BruteForceResult::TooFewKnowns);
     }
 
     let empty = board.unknowns() & board.cells_with_n_candidates(0);
     if !empty.is_empty() {
-        return BruteForceResult::UnsolvableCells(empty);
+        return Err(BruteForceResult::UnsolvableCells(empty));
+    }
+
+    if (1..=MAXIMUM_SOLUTIONS).contains(&max_solutions) {
+        Ok(max_solutions)
+    } else {
+        Ok(DEFAULT_MAXIMUM_SOLUTIONS)
     }
+}
 
-    if !(1..=MAXIMUM_SOLUTIONS).contains(&max_solutions) {
-        max_solutions = DEFAULT_MAXIMUM_SOLUTIONS;
+fn collect_solutions(solutions: Vec<Board>) -> BruteForceResult {
+    match solutions.len() {
+        0 => BruteForceResult::Unsolvable,
+        1 => BruteForceResult::Solved(Box::new(solutions[0])),
+        _ => BruteForceResult::MultipleSolutions(solutions),
     }
+}
+
+/// Finds solutions by applying each guess to a copy of the board with a [`Changer`],
+/// always trying the first unsolved cell.
+///
+/// This is much slower than [`find_brute_force`] but can log every step.
+pub fn find_brute_force_with_changer(
+    board: &Board,
+    log: bool,
+    pause: u32,
+    max_solutions: usize,
+) -> BruteForceResult {
+    let max_solutions = match check_board(board, max_solutions) {
+        Ok(max_solutions) => max_solutions,
+        Err(result) => return result,
+    };
 
     let cancelable = Cancelable::new();
     let changer = Changer::new(Options::errors());
@@ -108,10 +157,38 @@ pub fn find_brute_force(
         }
     }
 
-    match solutions.len() {
-        0 => BruteForceResult::Unsolvable,
-        1 => BruteForceResult::Solved(Box::new(solutions[0])),
-        _ => BruteForceResult::MultipleSolutions(solutions),
+    collect_solutions(solutions)
+}
+
+/// Counts the solutions to the board without storing them, stopping after `max_solutions`,
+/// and passes the digit in every cell of each solution to `visit`.
+///
+/// Unlike [`find_brute_force`], this accepts boards with too few givens
+/// to have a unique solution.
+pub fn count_solutions(
+    board: &Board,
+    max_solutions: usize,
+    visit: impl FnMut(&[u8; 81]),
+) -> SolutionCount {
+    count(board, max_solutions.max(1), visit)
+}
+
+/// The number of solutions found by [`count_solutions`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SolutionCount {
+    /// Every solution was counted.
+    Exact(usize),
+    /// The search stopped after reaching the maximum.
+    AtLeast(usize),
+    /// The search was canceled before trying every candidate.
+    Canceled(usize),
+}
+
+impl SolutionCount {
+    pub fn count(&self) -> usize {
+        match self {
+            Self::Exact(count) | Self::AtLeast(count) | Self::Canceled(count) => *count,
+        }
     }
 }
 
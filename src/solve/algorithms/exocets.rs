@@ -0,0 +1,219 @@
+use super::*;
+
+/// Two base cells in one segment of a block with three or four candidates
+/// force their two digits into two target cells in the other blocks of the band
+/// when every base digit is confined to at most two lines in the S-cells.
+///
+/// # Example
+///
+/// ```text
+///   123 456 789
+/// A BB· ··· ···  ←-- base cells A1 and A2 hold 1, 2 and 3 between them
+/// B ··· ·T· ···  ←-- target B5
+/// C ··· ··· T··  ←-- target C7 with companions B7 and C5 lacking every base digit
+///
+/// D ··S ·S· S··  ←-- the S-cells are the columns of the targets
+/// E ··S ·S· S··      and of the base segment's third cell outside the band
+/// ...
+/// ```
+///
+/// Each base digit placed in the base must appear in each S column outside the band
+/// or inside it, and only the targets remain inside the band, so the targets hold
+/// both base values.
+///
+/// - Non-base candidates are removed from the targets.
+/// - Base digits missing from both targets are removed from the base cells.
+pub fn find_junior_exocets(board: &Board, single: bool) -> Option<Effects> {
+    let mut effects = Effects::new();
+
+    for block in House::blocks_iter() {
+        for line in block.rows().iter().chain(block.columns().iter()) {
+            let segment = block.intersect(line);
+            let cross_shape = if line.is_row() {
+                Shape::Column
+            } else {
+                Shape::Row
+            };
+            let band = line.blocks().cells();
+            let (first, second) = block.houses(line.shape()).without(line).as_pair().unwrap();
+            let (left, right) = line.blocks().without(block).as_pair().unwrap();
+
+            for base in (segment & board.unknowns())
+                .iter()
+                .collect_vec()
+                .into_iter()
+                .combinations(2)
+            {
+                let base = base.into_iter().union_cells();
+                let knowns = board.all_candidates(base);
+                if !(3..=4).contains(&knowns.len()) {
+                    continue;
+                }
+
+                let escape = (segment - base).as_single().unwrap().house(cross_shape);
+                for (near, far) in [(first, second), (second, first)] {
+                    for t1 in left.intersect(near) & board.unknowns() {
+                        for t2 in right.intersect(far) & board.unknowns() {
+                            let exocet = Exocet {
+                                base,
+                                knowns,
+                                targets: CellSet::empty() + t1 + t2,
+                                companions: t1.house(cross_shape).intersect(far)
+                                    | t2.house(cross_shape).intersect(near),
+                                s_cells: (escape.cells()
+                                    | t1.house(cross_shape).cells()
+                                    | t2.house(cross_shape).cells())
+                                    - band,
+                                cross_lines: [escape, t1.house(cross_shape), t2.house(cross_shape)],
+                            };
+
+                            if let Some(action) = exocet.resolve(board, line.shape()) {
+                                if effects.add_action(action) && single {
+                                    return Some(effects);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    if effects.has_actions() {
+        Some(effects)
+    } else {
+        None
+    }
+}
+
+struct Exocet {
+    base: CellSet,
+    knowns: KnownSet,
+    targets: CellSet,
+    companions: CellSet,
+    s_cells: CellSet,
+    cross_lines: [House; 3],
+}
+
+impl Exocet {
+    fn resolve(&self, board: &Board, shape: Shape) -> Option<Action> {
+        let target_knowns = board.all_candidates(self.targets);
+        if self
+            .targets
+            .iter()
+            .any(|target| (board.candidates(target) & self.knowns).is_empty())
+        {
+            return None;
+        }
+        if !(board.all_candidates(self.companions) & self.knowns).is_empty()
+            || !(board.all_knowns(self.companions) & self.knowns).is_empty()
+        {
+            return None;
+        }
+        if self.knowns.iter().any(|known| {
+            !self.is_covered(
+                self.s_cells & (board.candidate_cells(known) | board.known_cells(known)),
+                shape,
+            )
+        }) {
+            return None;
+        }
+
+        let mut action = Action::new(Strategy::JuniorExocet);
+        for target in self.targets {
+            action.erase_knowns(target, board.candidates(target) - self.knowns);
+        }
+        for known in self.knowns - target_knowns {
+            action.erase_cells(self.base & board.candidate_cells(known), known);
+        }
+        if action.is_empty() {
+            return None;
+        }
+
+        for cell in self.base {
+            action.clue_cell_for_knowns(Verdict::Primary, cell, board.candidates(cell));
+        }
+        for cell in self.targets {
+            action.clue_cell_for_knowns(
+                Verdict::Secondary,
+                cell,
+                board.candidates(cell) & self.knowns,
+            );
+        }
+        Some(action)
+    }
+
+    /// Returns true if two lines parallel to the base segment or crossing it
+    /// through the S-cells hold all the cells.
+    fn is_covered(&self, cells: CellSet, shape: Shape) -> bool {
+        if cells.len() <= 2 {
+            return true;
+        }
+
+        let lines = cells
+            .iter()
+            .map(|cell| cell.house(shape))
+            .chain(self.cross_lines)
+            .unique()
+            .collect_vec();
+        lines
+            .iter()
+            .tuple_combinations()
+            .any(|(first, second)| cells.is_subset_of(first.cells() | second.cells()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::io::{Parse, Parser};
+    use crate::layout::cells::cell::cell;
+    use crate::layout::cells::cell_set::cells;
+    use crate::layout::values::known::known;
+    use crate::layout::values::known_set::knowns;
+
+    use super::*;
+
+    #[test]
+    fn junior_exocet() {
+        let parser = Parse::wiki().stop_on_error();
+        let (board, ..) = parser.parse(
+            "0305u009p070u0b00h0911u0u4ok6k03a4m4s0u00hv4p676u8bcncs0sa110hga2a05aa6a21oqo6h4411e8o9q1a4k4q46341e816og17askug09038k4k112ki49kaia6949sg12o412elkkik654215sgo0u81",
+        );
+
+        if let Some(got) = find_junior_exocets(&board, true) {
+            let mut action = Action::new(Strategy::JuniorExocet);
+            action.erase(cell!("B4"), known!("2"));
+            action.clue_cell_for_knowns(Verdict::Primary, cell!("C1"), knowns!("7 8 9"));
+            action.clue_cell_for_knowns(Verdict::Primary, cell!("C2"), knowns!("6 7 8 9"));
+            action.clue_cells_for_knowns(Verdict::Secondary, cells!("A7 B4"), knowns!("6 7 8 9"));
+
+            assert_eq!(format!("{:?}", action), format!("{:?}", got.actions()[0]));
+        } else {
+            panic!("not found");
+        }
+    }
+
+    #[test]
+    fn companions_must_lack_the_base_digits() {
+        let parser = Parse::wiki().stop_on_error();
+        // the junior_exocet board with companion A4 unsolved as 3 or base digit 6
+        let (board, effects, failed) = parser.parse(
+            "0305u028p070u0b00h0911u0u4ok6k03a4m4s0u00hv4p676u8bcncs0sa110hga2a05aa6a21oqo6h4411e8o9q1a4k4q46341e816og17askug09038k4k112ki49kaia6949sg12o412elkkik654215sgo0u81",
+        );
+        assert_eq!(None, failed);
+        assert!(!effects.has_errors());
+
+        assert_eq!(None, find_junior_exocets(&board, false));
+    }
+
+    #[test]
+    fn junior_exocet_in_stack() {
+        let parser = Parse::wiki().stop_on_error();
+        let (board, ..) = parser.parse(
+            "h6n6095mhmniskpkkk0hl6l65681l2k40921h4n4815shsno03hkkkp60hh6922192o441099efe76g11qdqak8m0moauam2cq0q05qgoi11hehe0h1e41hagc218121peh69uhupqksgmkm41oeg621guoq11gmgm",
+        );
+
+        let found = find_junior_exocets(&board, true).unwrap_or_default();
+        assert_eq!(cells!("G2"), found.erases_from_cells(known!("3")));
+    }
+}
@@ -12,6 +12,57 @@ pub fn find_jellyfish(board: &Board, single: bool) -> Option<Effects> {
     find_fish(board, single, 4, Strategy::Jellyfish)
 }
 
+pub fn find_finned_x_wings(board: &Board, single: bool) -> Option<Effects> {
+    find_finned_fish(board, single, 2, Strategy::FinnedXWing)
+}
+
+pub fn find_sashimi_x_wings(board: &Board, single: bool) -> Option<Effects> {
+    find_finned_fish(board, single, 2, Strategy::SashimiXWing)
+}
+
+pub fn find_finned_swordfish(board: &Board, single: bool) -> Option<Effects> {
+    find_finned_fish(board, single, 3, Strategy::FinnedSwordfish)
+}
+
+pub fn find_sashimi_swordfish(board: &Board, single: bool) -> Option<Effects> {
+    find_finned_fish(board, single, 3, Strategy::SashimiSwordfish)
+}
+
+pub fn find_finned_jellyfish(board: &Board, single: bool) -> Option<Effects> {
+    find_finned_fish(board, single, 4, Strategy::FinnedJellyfish)
+}
+
+pub fn find_sashimi_jellyfish(board: &Board, single: bool) -> Option<Effects> {
+    find_finned_fish(board, single, 4, Strategy::SashimiJellyfish)
+}
+
+/// Fish of size two or three with boxes in the base or cover sets
+/// alongside either rows or columns.
+pub fn find_franken_fish(board: &Board, single: bool) -> Option<Effects> {
+    let rows = Shape::Row
+        .house_iter()
+        .chain(Shape::Block.house_iter())
+        .collect_vec();
+    let columns = Shape::Column
+        .house_iter()
+        .chain(Shape::Block.house_iter())
+        .collect_vec();
+
+    find_complex_fish(
+        board,
+        single,
+        Strategy::FrankenFish,
+        &[(&rows, &columns), (&columns, &rows)],
+    )
+}
+
+/// Fish of size two or three that mix rows and columns in the base or cover sets.
+pub fn find_mutant_fish(board: &Board, single: bool) -> Option<Effects> {
+    let all = House::iter().collect_vec();
+
+    find_complex_fish(board, single, Strategy::MutantFish, &[(&all, &all)])
+}
+
 fn find_fish(board: &Board, single: bool, size: usize, strategy: Strategy) -> Option<Effects> {
     let mut effects = Effects::new();
 
@@ -102,6 +153,266 @@ fn check_houses(
     false
 }
 
+fn find_finned_fish(
+    board: &Board,
+    single: bool,
+    size: usize,
+    strategy: Strategy,
+) -> Option<Effects> {
+    let mut effects = Effects::new();
+
+    let rows = Shape::Row.house_iter().collect_vec();
+    let columns = Shape::Column.house_iter().collect_vec();
+    for (bases, covers) in [(&rows, &columns), (&columns, &rows)] {
+        if check_complex_houses(board, single, size, strategy, bases, covers, &mut effects) {
+            break;
+        }
+    }
+
+    if effects.has_actions() {
+        Some(effects)
+    } else {
+        None
+    }
+}
+
+fn find_complex_fish(
+    board: &Board,
+    single: bool,
+    strategy: Strategy,
+    shapes: &[(&Vec<House>, &Vec<House>)],
+) -> Option<Effects> {
+    let mut effects = Effects::new();
+
+    // searching size four as well made solving hard puzzles ten times slower
+    'sizes: for size in 2..=3 {
+        for (bases, covers) in shapes {
+            if check_complex_houses(board, single, size, strategy, bases, covers, &mut effects) {
+                break 'sizes;
+            }
+        }
+    }
+
+    if effects.has_actions() {
+        Some(effects)
+    } else {
+        None
+    }
+}
+
+/// Checks each combination of base houses that share no candidates
+/// against every set of cover houses that leaves any uncovered candidates
+/// (the fins) seeing a cell where the candidate may be removed.
+fn check_complex_houses(
+    board: &Board,
+    single: bool,
+    size: usize,
+    strategy: Strategy,
+    base_houses: &[House],
+    cover_houses: &[House],
+    effects: &mut Effects,
+) -> bool {
+    let cover_mask = house_mask(cover_houses);
+
+    for known in Known::iter() {
+        let candidate_cells = board.candidate_cells(known);
+        for bases in base_houses
+            .iter()
+            .map(|house| (*house, house.cells() & candidate_cells))
+            .filter(|(_, cells)| cells.len() >= 2)
+            .combinations(size)
+        {
+            let base_cells = bases.iter().map(|(_, cells)| *cells).union_cells();
+            if bases.iter().map(|(_, cells)| cells.len()).sum::<usize>() != base_cells.len() {
+                continue;
+            }
+
+            let bases = bases.iter().map(|(house, _)| *house).collect_vec();
+            if repeats_simpler_fish(strategy, &bases, base_cells) {
+                continue;
+            }
+            let mut search = CoverSearch {
+                candidate_cells,
+                base_cells,
+                allowed: cover_mask & !house_mask(&bases),
+                required: required_covers(strategy, &bases),
+                size,
+                covers: Vec::with_capacity(size),
+                found: Vec::new(),
+            };
+            search.cover(base_cells, CellSet::empty());
+
+            for covers in search.found {
+                let cover_cells = covers.iter().map(|house| house.cells()).union_cells();
+                let fins = base_cells - cover_cells;
+                if classify(&bases, &covers, base_cells, fins) != Some(strategy) {
+                    continue;
+                }
+
+                let mut erase = cover_cells & (candidate_cells - base_cells);
+                if !fins.is_empty() {
+                    erase &= fins.peers();
+                }
+                if erase.is_empty() {
+                    continue;
+                }
+
+                let mut action = Action::new(strategy);
+                action.erase_cells(erase, known);
+                action.clue_cells_for_known(Verdict::Secondary, base_cells - fins, known);
+                action.clue_cells_for_known(Verdict::Tertiary, fins, known);
+                bases.iter().for_each(|house| {
+                    action.clue_cells_for_known(
+                        Verdict::Related,
+                        house.cells() - base_cells - board.knowns(),
+                        known,
+                    );
+                });
+
+                if effects.add_action(action) && single {
+                    return true;
+                }
+            }
+        }
+    }
+
+    false
+}
+
+/// Returns true when a Franken or Mutant fish would only repeat a simpler one
+/// because its base candidates fit in one house, its base houses are only rows
+/// or only columns like a basic or finned fish, or a Mutant fish does not mix
+/// shapes in its base houses.
+fn repeats_simpler_fish(strategy: Strategy, bases: &[House], base_cells: CellSet) -> bool {
+    if !matches!(strategy, Strategy::FrankenFish | Strategy::MutantFish) {
+        return false;
+    }
+    if base_cells.share_any_house() {
+        return true;
+    }
+
+    let shape = bases[0].shape();
+    if bases.iter().any(|house| house.shape() != shape) {
+        return false;
+    }
+    strategy == Strategy::MutantFish || shape != Shape::Block
+}
+
+/// Finds the sets of cover houses for one set of base houses.
+struct CoverSearch {
+    candidate_cells: CellSet,
+    base_cells: CellSet,
+    allowed: u32,
+    required: u32,
+    size: usize,
+    covers: Vec<House>,
+    found: Vec<Vec<House>>,
+}
+
+impl CoverSearch {
+    /// Covers the first remaining candidate with each of its houses
+    /// or leaves it as a fin as long as the fins share a peer to erase.
+    fn cover(&mut self, remaining: CellSet, fins: CellSet) {
+        if !fins.is_empty() && (fins.peers() & (self.candidate_cells - self.base_cells)).is_empty()
+        {
+            return;
+        }
+        let mask = house_mask(&self.covers);
+        if self.covers.len() == self.size {
+            if !self.found.iter().any(|covers| house_mask(covers) == mask) {
+                self.found.push(self.covers.clone());
+            }
+            return;
+        }
+        let Some(cell) = remaining.first() else {
+            // covered by fewer houses
+            return;
+        };
+
+        // the last cover must be a required house when none is used yet
+        let mut allowed = self.allowed;
+        if self.required != 0 && mask & self.required == 0 && self.covers.len() + 1 == self.size {
+            allowed &= self.required;
+        }
+        for house in cell.houses() {
+            let bit = house_bit(house);
+            if allowed & bit != 0 {
+                self.allowed &= !bit;
+                self.covers.push(house);
+                self.cover(remaining - house.cells(), fins - house.cells());
+                self.covers.pop();
+                self.allowed |= bit;
+            }
+        }
+        self.cover(remaining - cell, fins + cell);
+    }
+}
+
+/// Returns the cover houses of which a Mutant fish must use one to mix rows
+/// and columns on one side when its base houses hold only one of them.
+fn required_covers(strategy: Strategy, bases: &[House]) -> u32 {
+    if strategy != Strategy::MutantFish {
+        return 0;
+    }
+    let has = |shape| bases.iter().any(|house| house.shape() == shape);
+    match (has(Shape::Row), has(Shape::Column)) {
+        (true, false) => house_mask(&Shape::Row.house_iter().collect_vec()),
+        (false, true) => house_mask(&Shape::Column.house_iter().collect_vec()),
+        _ => 0,
+    }
+}
+
+fn house_bit(house: House) -> u32 {
+    1 << (9 * house.shape().usize() + house.usize())
+}
+
+fn house_mask(houses: &[House]) -> u32 {
+    houses
+        .iter()
+        .fold(0, |mask, house| mask | house_bit(*house))
+}
+
+/// Names the fish by the shapes of its houses and how its fins fall.
+fn classify(
+    bases: &[House],
+    covers: &[House],
+    base_cells: CellSet,
+    fins: CellSet,
+) -> Option<Strategy> {
+    let shapes = |houses: &[House]| {
+        houses.iter().fold([false; 3], |mut shapes, house| {
+            shapes[house.shape().usize()] = true;
+            shapes
+        })
+    };
+    let (base, cover) = (shapes(bases), shapes(covers));
+    let [row, column, block] = [0, 1, 2].map(|shape| base[shape] || cover[shape]);
+
+    // rows and columns may only be split between the base and cover houses
+    let split = (!base[1] && !cover[0]) || (!base[0] && !cover[1]);
+    if !split {
+        return Some(Strategy::MutantFish);
+    }
+    if block {
+        return Some(Strategy::FrankenFish);
+    }
+    if !row || !column || fins.is_empty() {
+        return None;
+    }
+
+    let sashimi = bases
+        .iter()
+        .any(|house| (house.cells() & (base_cells - fins)).len() <= 1);
+    Some(match (bases.len(), sashimi) {
+        (2, false) => Strategy::FinnedXWing,
+        (2, true) => Strategy::SashimiXWing,
+        (3, false) => Strategy::FinnedSwordfish,
+        (3, true) => Strategy::SashimiSwordfish,
+        (_, false) => Strategy::FinnedJellyfish,
+        (_, true) => Strategy::SashimiJellyfish,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use crate::io::{Parse, Parser};
@@ -126,7 +437,7 @@ mod tests {
             ",
         );
 
-        let found = find_x_wings(&board, true).unwrap_or(Effects::new());
+        let found = find_x_wings(&board, true).unwrap_or_default();
         assert_eq!(
             cells!("A4 E4 H4 J4 D8 E8 H8 J8"),
             found.erases_from_cells(known!("7"))
@@ -149,7 +460,7 @@ mod tests {
             ",
         );
 
-        let found = find_swordfish(&board, true).unwrap_or(Effects::new());
+        let found = find_swordfish(&board, true).unwrap_or_default();
         assert_eq!(
             cells!("B2 B8 C2 C6 C8 C9 D6"),
             found.erases_from_cells(known!("8"))
@@ -172,10 +483,55 @@ mod tests {
             ",
         );
 
-        let found = find_jellyfish(&board, true).unwrap_or(Effects::new());
+        let found = find_jellyfish(&board, true).unwrap_or_default();
         assert_eq!(
             cells!("B1 B5 B8 C8 C9 G1 G8 H1 H5 H9"),
             found.erases_from_cells(known!("2"))
         );
     }
+
+    #[test]
+    fn finned_x_wing() {
+        let parser = Parse::wiki().stop_on_error();
+        let (board, ..) = parser.parse(
+            "ik2g0311418009ikg4815olk2103golghkl4no7olggo04go81jil25kg15k03086g5081545ododgsgo005l2ha2103c821s0o011k0gc0hngfi09s0p0k205jip2j005p0o80hgaj241palgditgsc21kahihqpa",
+        );
+
+        let found = find_finned_x_wings(&board, true).unwrap_or_default();
+        assert_eq!(cells!("A8"), found.erases_from_cells(known!("2")));
+        assert!(find_sashimi_x_wings(&board, true).is_none());
+    }
+
+    #[test]
+    fn sashimi_x_wing() {
+        let parser = Parse::wiki().stop_on_error();
+        let (board, ..) = parser.parse(
+            "ik2g0311418009igg4815glk2103085ghgl4087050g0040g8132525kg15k03082050815050d0d00gg005520821030821408011g0040hmg6i098010k205iig2j005p0080hg23241p2lgditg0421k21ihipa",
+        );
+
+        let found = find_sashimi_x_wings(&board, true).unwrap_or_default();
+        assert_eq!(cells!("H7"), found.erases_from_cells(known!("1")));
+    }
+
+    #[test]
+    fn franken_fish() {
+        let parser = Parse::wiki().stop_on_error();
+        let (board, ..) = parser.parse(
+            "ik2g031141og09ikg4815olk2103golghkl4ns7olkgsgcgo81jml65sg15k03086o50815c5ododgsoo805l2ha2103c821s8o811k0gc0hngfi09s0p0s205jip2j005p0o80hoaj241palgditgsc21sahihqpa",
+        );
+
+        let found = find_franken_fish(&board, true).unwrap_or_default();
+        assert_eq!(cells!("C8"), found.erases_from_cells(known!("2")));
+    }
+
+    #[test]
+    fn mutant_fish() {
+        let parser = Parse::wiki().stop_on_error();
+        let (board, ..) = parser.parse(
+            "ik2g031141og09ikg4815olk2103golghkl4ns7olkgsgcgo81jml65sg15k03086o50815c5ododgsoo805l2ha2103c821s8o811k0gc0hngfi09s0p0s205jip2j005p0o80hoaj241palgditgsc21sahihqpa",
+        );
+
+        let found = find_mutant_fish(&board, true).unwrap_or_default();
+        assert_eq!(cells!("C8 C9"), found.erases_from_cells(known!("2")));
+    }
 }
@@ -0,0 +1,287 @@
+use super::*;
+
+/// The most rounds of singles and intersection removals
+/// applied after assuming a candidate is true or false.
+const MAX_DEPTH: usize = 20;
+
+/// The most candidates in a cell or cells in a house
+/// that may be assumed in turn by a forcing chain.
+const MAX_BRANCHES: usize = 3;
+
+/// Removes each candidate that leads to a contradiction when assumed to be true.
+pub fn find_nishios(board: &Board, single: bool) -> Option<Effects> {
+    let mut effects = Effects::new();
+
+    for (cell, candidates) in board.unknown_iter() {
+        for known in candidates {
+            let branch = Implications::assume(board, cell, known, true);
+            if !branch.contradiction {
+                continue;
+            }
+
+            let mut action = Action::new_erase(Strategy::Nishio, cell, known);
+            branch.clue(&mut action, Verdict::Secondary, &mut [CellSet::empty(); 9]);
+
+            if effects.add_action(action) && single {
+                return Some(effects);
+            }
+        }
+    }
+
+    if effects.has_actions() {
+        Some(effects)
+    } else {
+        None
+    }
+}
+
+/// Assumes each candidate in a cell is true in turn
+/// and keeps whatever follows from all of them.
+pub fn find_cell_forcing_chains(board: &Board, single: bool) -> Option<Effects> {
+    let mut effects = Effects::new();
+
+    for (cell, candidates) in board.unknown_iter() {
+        if candidates.len() > MAX_BRANCHES {
+            continue;
+        }
+
+        let branches = candidates
+            .iter()
+            .map(|known| Implications::assume(board, cell, known, true))
+            .collect_vec();
+        if let Some(action) = conclude(Strategy::CellForcingChain, &branches) {
+            if effects.add_action(action) && single {
+                return Some(effects);
+            }
+        }
+    }
+
+    if effects.has_actions() {
+        Some(effects)
+    } else {
+        None
+    }
+}
+
+/// Assumes each cell holding a candidate in a house is solved in turn
+/// and keeps whatever follows from all of them.
+pub fn find_unit_forcing_chains(board: &Board, single: bool) -> Option<Effects> {
+    let mut effects = Effects::new();
+
+    for house in House::iter() {
+        for known in Known::iter() {
+            let cells = board.house_candidate_cells(house, known);
+            if !(2..=MAX_BRANCHES).contains(&cells.len()) {
+                continue;
+            }
+
+            let branches = cells
+                .iter()
+                .map(|cell| Implications::assume(board, cell, known, true))
+                .collect_vec();
+            if let Some(action) = conclude(Strategy::UnitForcingChain, &branches) {
+                if effects.add_action(action) && single {
+                    return Some(effects);
+                }
+            }
+        }
+    }
+
+    if effects.has_actions() {
+        Some(effects)
+    } else {
+        None
+    }
+}
+
+/// Assumes a candidate is true and then false
+/// and keeps whatever follows from both.
+pub fn find_digit_forcing_chains(board: &Board, single: bool) -> Option<Effects> {
+    let mut effects = Effects::new();
+
+    for (cell, candidates) in board.unknown_iter() {
+        for known in candidates {
+            let branches = [
+                Implications::assume(board, cell, known, true),
+                Implications::assume(board, cell, known, false),
+            ];
+            if let Some(action) = conclude(Strategy::DigitForcingChain, &branches) {
+                if effects.add_action(action) && single {
+                    return Some(effects);
+                }
+            }
+        }
+    }
+
+    if effects.has_actions() {
+        Some(effects)
+    } else {
+        None
+    }
+}
+
+/// Solves the candidates solved by every branch and removes those removed by every branch.
+///
+/// Branches that lead to a contradiction are left to [`find_nishios()`].
+fn conclude(strategy: Strategy, branches: &[Implications]) -> Option<Action> {
+    if branches.iter().any(|branch| branch.contradiction) {
+        return None;
+    }
+
+    let mut action = Action::new(strategy);
+    let mut concluded = [CellSet::empty(); 9];
+    let solved = Known::iter()
+        .map(|known| {
+            branches
+                .iter()
+                .map(|branch| branch.solved[known.usize()])
+                .intersection()
+        })
+        .collect_vec();
+    let all_solved = solved.iter().copied().union_cells();
+
+    for known in Known::iter() {
+        let erased = branches
+            .iter()
+            .map(|branch| branch.erased[known.usize()])
+            .intersection()
+            - all_solved;
+
+        for cell in solved[known.usize()] {
+            action.set(cell, known);
+        }
+        action.erase_cells(erased, known);
+        concluded[known.usize()] = solved[known.usize()] | erased;
+    }
+    if action.is_empty() {
+        return None;
+    }
+
+    for (branch, verdict) in
+        branches
+            .iter()
+            .zip([Verdict::Secondary, Verdict::Tertiary, Verdict::Related])
+    {
+        branch.clue(&mut action, verdict, &mut concluded);
+    }
+    Some(action)
+}
+
+/// The candidates solved and removed by assuming one candidate is true or false.
+struct Implications {
+    cell: Cell,
+    known: Known,
+    solved: [CellSet; 9],
+    erased: [CellSet; 9],
+    contradiction: bool,
+}
+
+impl Implications {
+    /// Applies the assumption followed by singles and intersection removals
+    /// up to [`MAX_DEPTH`] rounds and compares the result to the original board.
+    fn assume(board: &Board, cell: Cell, known: Known, on: bool) -> Self {
+        let changer = Changer::new(Options::all());
+        let strategy = if on { Strategy::Solve } else { Strategy::Erase };
+        let assumption = if on {
+            Action::new_set(strategy, cell, known)
+        } else {
+            Action::new_erase(strategy, cell, known)
+        };
+
+        let (after, contradiction) = match changer.apply_to_depth(board, &assumption, MAX_DEPTH) {
+            ChangeResult::None => (*board, false),
+            ChangeResult::Valid(after, _) => (*after, false),
+            ChangeResult::Invalid(_, after, _, errors) => {
+                // a deadly rectangle is only a contradiction when assuming a unique solution
+                let broken = errors
+                    .errors_iter()
+                    .any(|error| !matches!(error, Error::DeadlyRectangle(_)));
+                (*after, broken)
+            }
+        };
+
+        let mut solved = [CellSet::empty(); 9];
+        let mut erased = [CellSet::empty(); 9];
+        for known in Known::iter() {
+            solved[known.usize()] = after.solved_cells(known) & board.unknowns();
+            erased[known.usize()] =
+                board.candidate_cells(known) - after.candidate_cells(known) - solved[known.usize()];
+        }
+
+        Implications {
+            cell,
+            known,
+            solved,
+            erased,
+            contradiction,
+        }
+    }
+
+    /// Marks the assumed candidate and the cells it solved, skipping those already marked.
+    fn clue(&self, action: &mut Action, verdict: Verdict, clued: &mut [CellSet; 9]) {
+        if !clued[self.known.usize()].has(self.cell) {
+            action.clue_cell_for_known(Verdict::Primary, self.cell, self.known);
+            clued[self.known.usize()] += self.cell;
+        }
+        for known in Known::iter() {
+            let cells = self.solved[known.usize()] - clued[known.usize()];
+            action.clue_cells_for_known(verdict, cells, known);
+            clued[known.usize()] |= cells;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::io::{Parse, Parser};
+    use crate::layout::cells::cell_set::cells;
+    use crate::layout::values::known::known;
+
+    use super::*;
+
+    const BOARD: &str = "ik2g031141og09ikg4815olk2103golghkl4ns7olkgsgcgo81jml65sg15k03086o50815c5ododgsoo805l2ha2103c821s8o811k0gc0hngfi09s0p0s205jip2j005p0o80hoaj241palgditgsc21sahihqpa";
+
+    #[test]
+    fn nishio() {
+        let parser = Parse::wiki().stop_on_error();
+        let (board, ..) = parser.parse(
+            "0g21g00510814848020682864o0o4oi0i011410910g02103840k8k0c9ka45og14kfc03acgc94a458034c0hncqcge1m411o8021hchcgc21g20a8005goh0hg4181g40h024111icicic10400c200ogo0381gk",
+        );
+
+        let found = find_nishios(&board, true).unwrap_or_default();
+        assert_eq!(cells!("D4"), found.erases_from_cells(known!("3")));
+    }
+
+    #[test]
+    fn cell_forcing_chain() {
+        let parser = Parse::wiki().stop_on_error();
+        let (board, ..) = parser.parse(BOARD);
+
+        let found = find_cell_forcing_chains(&board, true).unwrap_or_default();
+        assert_eq!(
+            cells!("A6 A8 B7 C8 C9 H9 J9"),
+            found.erases_from_cells(known!("9"))
+        );
+    }
+
+    #[test]
+    fn unit_forcing_chain() {
+        let parser = Parse::wiki().stop_on_error();
+        let (board, ..) = parser.parse(BOARD);
+
+        let found = find_unit_forcing_chains(&board, true).unwrap_or_default();
+        assert_eq!(cells!("J8"), found.erases_from_cells(known!("3")));
+    }
+
+    #[test]
+    fn digit_forcing_chain() {
+        let parser = Parse::wiki().stop_on_error();
+        let (board, ..) = parser.parse(BOARD);
+
+        let found = find_digit_forcing_chains(&board, true).unwrap_or_default();
+        assert_eq!(
+            cells!("A6 A8 B7 C8 C9 H9 J9"),
+            found.erases_from_cells(known!("9"))
+        );
+    }
+}
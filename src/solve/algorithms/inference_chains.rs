@@ -0,0 +1,400 @@
+use std::collections::VecDeque;
+
+use super::*;
+
+/// Single-digit chains and loops using only the links between cells in a house.
+pub fn find_x_cycles(board: &Board, single: bool) -> Option<Effects> {
+    let mut effects = Effects::new();
+
+    for known in Known::iter() {
+        let graph = Graph::new(board, Some(known));
+        if graph.resolve(board, Strategy::XCycle, single, &mut effects) {
+            return Some(effects);
+        }
+    }
+
+    if effects.has_actions() {
+        Some(effects)
+    } else {
+        None
+    }
+}
+
+/// Chains and loops that alternate between strong and weak links
+/// across any digit, using both house links and those within a cell.
+pub fn find_alternating_inference_chains(board: &Board, single: bool) -> Option<Effects> {
+    let mut effects = Effects::new();
+
+    let graph = Graph::new(board, None);
+    graph.resolve(
+        board,
+        Strategy::AlternatingInferenceChain,
+        single,
+        &mut effects,
+    );
+
+    if effects.has_actions() {
+        Some(effects)
+    } else {
+        None
+    }
+}
+
+/// One candidate in a cell.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+struct Node {
+    cell: Cell,
+    known: Known,
+}
+
+impl Node {
+    const COUNT: usize = 81 * 9;
+
+    fn new(cell: Cell, known: Known) -> Self {
+        Node { cell, known }
+    }
+
+    fn from_index(index: usize) -> Self {
+        Node {
+            cell: Cell::new((index / 9) as u8),
+            known: Known::from_index((index % 9) as u32),
+        }
+    }
+
+    fn index(&self) -> usize {
+        9 * self.cell.usize() + self.known.usize()
+    }
+}
+
+/// Links every candidate to those that cannot both be true (weak)
+/// and those that cannot both be false (strong).
+///
+/// Every strong link is also weak and may be used as either in a chain.
+struct Graph {
+    starts: Vec<usize>,
+    strong: Vec<Vec<usize>>,
+    weak: Vec<Vec<usize>>,
+}
+
+impl Graph {
+    /// Builds the links for a single digit or all digits.
+    fn new(board: &Board, digit: Option<Known>) -> Self {
+        let mut starts = Vec::new();
+        let mut strong = vec![Vec::new(); Node::COUNT];
+        let mut weak = vec![Vec::new(); Node::COUNT];
+
+        for cell in board.unknowns() {
+            let candidates = board.candidates(cell);
+            for known in candidates {
+                if digit.is_some_and(|digit| digit != known) {
+                    continue;
+                }
+
+                let index = Node::new(cell, known).index();
+                starts.push(index);
+
+                let mut conjugates = CellSet::empty();
+                for house in cell.houses() {
+                    let cells = board.house_candidate_cells(house, known);
+                    if cells.len() == 2 {
+                        conjugates |= cells - cell;
+                    }
+                }
+                strong[index].extend(conjugates.iter().map(|c| Node::new(c, known).index()));
+                weak[index].extend(
+                    (cell.peers() & board.candidate_cells(known))
+                        .iter()
+                        .map(|c| Node::new(c, known).index()),
+                );
+
+                if digit.is_none() {
+                    let others = candidates - known;
+                    if others.len() == 1 {
+                        strong[index].push(Node::new(cell, others.as_single().unwrap()).index());
+                    }
+                    weak[index].extend(others.iter().map(|k| Node::new(cell, k).index()));
+                }
+            }
+        }
+
+        Graph {
+            starts,
+            strong,
+            weak,
+        }
+    }
+
+    /// Finds every chain from each candidate, assuming it is false,
+    /// and adds the shortest ones that together cover all of their deductions.
+    fn resolve(
+        &self,
+        board: &Board,
+        strategy: Strategy,
+        single: bool,
+        effects: &mut Effects,
+    ) -> bool {
+        let mut found = Vec::new();
+        for start in &self.starts {
+            self.search(board, *start, &mut found);
+        }
+
+        let mut erased = [CellSet::empty(); 9];
+        let mut solved = CellSet::empty();
+        for deduction in found.iter().sorted_by(|left, right| {
+            left.chain
+                .len()
+                .cmp(&right.chain.len())
+                .then(right.erases.len().cmp(&left.erases.len()))
+        }) {
+            let new_erases = deduction
+                .erases
+                .iter()
+                .any(|node| !erased[node.known.usize()].has(node.cell));
+            let new_set = deduction.set.is_some_and(|node| !solved.has(node.cell));
+            if !new_erases && !new_set {
+                continue;
+            }
+
+            let mut action = Action::new(strategy);
+            for node in &deduction.erases {
+                action.erase(node.cell, node.known);
+                erased[node.known.usize()].add(node.cell);
+            }
+            if let Some(node) = deduction.set {
+                action.set(node.cell, node.known);
+                solved.add(node.cell);
+            }
+            for (node, on) in &deduction.chain {
+                let verdict = if *on {
+                    Verdict::Secondary
+                } else {
+                    Verdict::Tertiary
+                };
+                action.clue_cell_for_known(verdict, node.cell, node.known);
+            }
+
+            if effects.add_action(action) && single {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Searches breadth-first so each candidate is reached by the shortest chain,
+    /// alternating strong links from false candidates and weak links from true ones.
+    fn search(&self, board: &Board, start: usize, found: &mut Vec<Deduction>) {
+        let mut steps = vec![Step {
+            index: start,
+            on: false,
+            parent: None,
+            len: 1,
+        }];
+        let mut visited = [vec![false; Node::COUNT], vec![false; Node::COUNT]];
+        visited[0][start] = true;
+
+        let mut queue = VecDeque::from([0]);
+        while let Some(current) = queue.pop_front() {
+            let step = steps[current];
+            let links = if step.on {
+                &self.weak[step.index]
+            } else {
+                &self.strong[step.index]
+            };
+
+            for next in links {
+                let on = !step.on;
+                if *next == start {
+                    if on && step.len >= 3 {
+                        // the start cannot be false, so it must be true
+                        let chain = chain(&steps, current);
+                        found.push(Deduction {
+                            chain,
+                            erases: Vec::new(),
+                            set: Some(Node::from_index(start)),
+                        });
+                    }
+                    continue;
+                }
+                if visited[on as usize][*next] || in_chain(&steps, current, *next) {
+                    continue;
+                }
+                visited[on as usize][*next] = true;
+
+                steps.push(Step {
+                    index: *next,
+                    on,
+                    parent: Some(current),
+                    len: step.len + 1,
+                });
+                let added = steps.len() - 1;
+                queue.push_back(added);
+
+                if on && step.len + 1 >= 4 {
+                    self.deduce(board, &steps, added, found);
+                }
+            }
+        }
+    }
+
+    /// Either the start or the end of the chain must be true, so every candidate
+    /// that sees both is false. When the end sees the start, the chain forms a loop
+    /// where every weak link is also strong.
+    fn deduce(&self, board: &Board, steps: &[Step], end: usize, found: &mut Vec<Deduction>) {
+        let chain = chain(steps, end);
+        let first = chain[0].0;
+        let last = chain[chain.len() - 1].0;
+
+        if self.weak[last.index()].contains(&first.index()) {
+            let mut erases = Vec::new();
+            for (i, (node, on)) in chain.iter().enumerate() {
+                if !on {
+                    continue;
+                }
+                let next = chain[(i + 1) % chain.len()].0;
+                for erase in seen_by_both(board, *node, next) {
+                    if !erases.contains(&erase) && !chain.iter().any(|(n, _)| *n == erase) {
+                        erases.push(erase);
+                    }
+                }
+            }
+            if !erases.is_empty() {
+                found.push(Deduction {
+                    chain,
+                    erases,
+                    set: None,
+                });
+            }
+            return;
+        }
+
+        let erases = seen_by_both(board, first, last);
+        if !erases.is_empty() {
+            found.push(Deduction {
+                chain,
+                erases,
+                set: None,
+            });
+        }
+    }
+}
+
+/// One candidate reached while searching with a link back to the one before it.
+#[derive(Clone, Copy)]
+struct Step {
+    index: usize,
+    on: bool,
+    parent: Option<usize>,
+    len: usize,
+}
+
+/// Returns the candidates from the start of the chain to this step
+/// and whether each is true when the start is false.
+fn chain(steps: &[Step], end: usize) -> Vec<(Node, bool)> {
+    let mut chain = Vec::with_capacity(steps[end].len);
+    let mut current = Some(end);
+    while let Some(index) = current {
+        let step = steps[index];
+        chain.push((Node::from_index(step.index), step.on));
+        current = step.parent;
+    }
+    chain.reverse();
+    chain
+}
+
+fn in_chain(steps: &[Step], end: usize, index: usize) -> bool {
+    let mut current = Some(end);
+    while let Some(i) = current {
+        if steps[i].index == index {
+            return true;
+        }
+        current = steps[i].parent;
+    }
+    false
+}
+
+/// Returns the candidates other than these two that cannot be true if either one is.
+fn seen_by_both(board: &Board, a: Node, b: Node) -> Vec<Node> {
+    if a.known == b.known {
+        (a.cell.peers() & b.cell.peers() & board.candidate_cells(a.known))
+            .iter()
+            .filter(|cell| *cell != a.cell && *cell != b.cell)
+            .map(|cell| Node::new(cell, a.known))
+            .collect()
+    } else if a.cell == b.cell {
+        (board.candidates(a.cell) - a.known - b.known)
+            .iter()
+            .map(|known| Node::new(a.cell, known))
+            .collect()
+    } else if a.cell.peers().has(b.cell) {
+        let mut seen = Vec::new();
+        if board.is_candidate(a.cell, b.known) {
+            seen.push(Node::new(a.cell, b.known));
+        }
+        if board.is_candidate(b.cell, a.known) {
+            seen.push(Node::new(b.cell, a.known));
+        }
+        seen
+    } else {
+        Vec::new()
+    }
+}
+
+/// The chain and the candidates it removes or solves.
+struct Deduction {
+    chain: Vec<(Node, bool)>,
+    erases: Vec<Node>,
+    set: Option<Node>,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::io::{Parse, Parser};
+    use crate::layout::cells::cell::cell;
+    use crate::layout::cells::cell_set::cells;
+    use crate::layout::values::known::known;
+
+    use super::*;
+
+    #[test]
+    fn x_cycle() {
+        let parser = Parse::wiki().stop_on_error();
+        let (board, ..) = parser.parse(
+            "40h0h00g0280200c0c03218109g10540100g09050h2140108002g0i08038033sigh0244005l8788138i0h00h030h02j0h41441098024i00g0241810904i01181k0m4h034020hi82811g82cg42kig034180",
+        );
+
+        if let Some(got) = find_x_cycles(&board, true) {
+            let mut action = Action::new(Strategy::XCycle);
+            action.erase(cell!("H4"), known!("9"));
+            action.clue_cells_for_known(Verdict::Secondary, cells!("F3 G1 H8"), known!("9"));
+            action.clue_cells_for_known(Verdict::Tertiary, cells!("D1 F4 G8"), known!("9"));
+
+            assert_eq!(format!("{:?}", action), format!("{:?}", got.actions()[0]));
+        } else {
+            panic!("not found");
+        }
+    }
+
+    #[test]
+    fn alternating_inference_chain() {
+        let parser = Parse::wiki().stop_on_error();
+        let (board, ..) = parser.parse(
+            "0g21g00510814848020686864o0o4oi0i011410910g02103840k8k0c9ka45og14sfc03acgc94a458034c0hncqcge1m411o8021hchcgc21g20a8005goh0hg4181g40h024111icicic10400c200ogo0381gk",
+        );
+
+        if let Some(got) = find_alternating_inference_chains(&board, true) {
+            let mut action = Action::new(Strategy::AlternatingInferenceChain);
+            action.erase(cell!("B2"), known!("2"));
+            action.clue_cells_for_known(Verdict::Secondary, cells!("B1 G3"), known!("1"));
+            action.clue_cell_for_known(Verdict::Secondary, cell!("G2"), known!("9"));
+            action.clue_cell_for_known(Verdict::Secondary, cell!("H2"), known!("2"));
+            action.clue_cells_for_known(Verdict::Tertiary, cells!("B3 G2"), known!("1"));
+            action.clue_cell_for_known(Verdict::Tertiary, cell!("B1"), known!("2"));
+            action.clue_cell_for_known(Verdict::Tertiary, cell!("H2"), known!("9"));
+
+            assert_eq!(format!("{:?}", action), format!("{:?}", got.actions()[0]));
+        } else {
+            panic!("not found");
+        }
+    }
+}
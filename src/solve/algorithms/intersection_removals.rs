@@ -143,7 +143,7 @@ mod tests {
             ",
         );
 
-        let found = find_intersection_removals(&board, false).unwrap_or(Effects::new());
+        let found = find_intersection_removals(&board, false).unwrap_or_default();
         assert_eq!(cells!("B8 B9"), found.erases_from_cells(known!("1")));
         assert_eq!(cells!(""), found.erases_from_cells(known!("2")));
         assert_eq!(cells!("D5 E5 F5"), found.erases_from_cells(known!("3")));
@@ -0,0 +1,210 @@
+use super::*;
+
+/// Colors every candidate reachable through bi-value cells and bi-location houses
+/// with two alternating colors and applies the six 3D Medusa rules.
+pub fn find_medusas(board: &Board, single: bool) -> Option<Effects> {
+    let mut effects = Effects::new();
+
+    let links = strong_links(board);
+    let mut clustered = [CellSet::empty(); 9];
+
+    for cell in board.unknowns() {
+        for known in board.candidates(cell) {
+            if clustered[known.usize()].has(cell) || links[node(cell, known)].is_empty() {
+                continue;
+            }
+
+            let Some(cluster) = Cluster::color(&links, cell, known) else {
+                // an odd loop means the puzzle is already broken
+                continue;
+            };
+            for known in Known::iter() {
+                clustered[known.usize()] |= cluster.all(known);
+            }
+            if cluster.knowns().len() < 2 {
+                // simple coloring
+                continue;
+            }
+
+            let mut action = Action::new(Strategy::Medusa3D);
+            if let Some(color) = (0..2).find(|color| cluster.is_false(board, *color)) {
+                for known in Known::iter() {
+                    action.erase_cells(cluster.colors[color][known.usize()], known);
+                }
+            } else {
+                cluster.erase_uncolored(board, &mut action);
+            }
+            if action.is_empty() {
+                continue;
+            }
+
+            for known in Known::iter() {
+                let [first, second] = cluster.colors.map(|colors| colors[known.usize()]);
+                action.clue_cells_for_known(Verdict::Secondary, first, known);
+                action.clue_cells_for_known(Verdict::Tertiary, second, known);
+            }
+
+            if effects.add_action(action) && single {
+                return Some(effects);
+            }
+        }
+    }
+
+    if effects.has_actions() {
+        Some(effects)
+    } else {
+        None
+    }
+}
+
+/// Candidates colored by which of two groups must be true.
+struct Cluster {
+    colors: [[CellSet; 9]; 2],
+}
+
+impl Cluster {
+    /// Colors the candidates linked to this one, returning `None`
+    /// if any candidate is reached with both colors.
+    fn color(links: &[Vec<(Cell, Known)>], cell: Cell, known: Known) -> Option<Self> {
+        let mut colors = [[CellSet::empty(); 9]; 2];
+        let mut stack = vec![(cell, known, 0)];
+        colors[0][known.usize()] += cell;
+
+        while let Some((cell, known, color)) = stack.pop() {
+            let other = 1 - color;
+            for (next, next_known) in &links[node(cell, known)] {
+                if colors[color][next_known.usize()].has(*next) {
+                    return None;
+                }
+                if !colors[other][next_known.usize()].has(*next) {
+                    colors[other][next_known.usize()] += *next;
+                    stack.push((*next, *next_known, other));
+                }
+            }
+        }
+
+        Some(Cluster { colors })
+    }
+
+    fn all(&self, known: Known) -> CellSet {
+        self.colors[0][known.usize()] | self.colors[1][known.usize()]
+    }
+
+    fn cells(&self, color: usize) -> CellSet {
+        self.colors[color].iter().copied().union_cells()
+    }
+
+    fn knowns(&self) -> KnownSet {
+        Known::iter()
+            .filter(|known| !self.all(*known).is_empty())
+            .union()
+    }
+
+    /// Returns true if the color appears twice in a cell (rule 1) or a house (rule 2),
+    /// or if every candidate in an uncolored cell sees it (rule 6).
+    fn is_false(&self, board: &Board, color: usize) -> bool {
+        let colored = &self.colors[color];
+
+        let twice_in_cell = colored
+            .iter()
+            .tuple_combinations()
+            .any(|(first, second)| first.has_any(*second));
+        let twice_in_house = colored
+            .iter()
+            .any(|cells| cells.iter().any(|cell| cell.peers().has_any(*cells)));
+        if twice_in_cell || twice_in_house {
+            return true;
+        }
+
+        let all = self.cells(0) | self.cells(1);
+        (board.unknowns() - all).iter().any(|cell| {
+            board
+                .candidates(cell)
+                .iter()
+                .all(|known| cell.peers().has_any(colored[known.usize()]))
+        })
+    }
+
+    /// Removes candidates that see both colors (rule 4), share a cell with
+    /// both colors (rule 3), or see one color while sharing a cell with the other (rule 5).
+    fn erase_uncolored(&self, board: &Board, action: &mut Action) {
+        let both = self.cells(0) & self.cells(1);
+
+        for known in Known::iter() {
+            let [first, second] = self.colors.map(|colors| colors[known.usize()]);
+            let uncolored = board.candidate_cells(known) - first - second;
+
+            action.erase_cells(
+                uncolored & first.union_peers() & second.union_peers(),
+                known,
+            );
+            action.erase_cells(uncolored & both, known);
+            action.erase_cells(
+                uncolored & self.cells(0) & second.union_peers()
+                    | uncolored & self.cells(1) & first.union_peers(),
+                known,
+            );
+        }
+    }
+}
+
+/// Returns the conjugate candidates for every candidate: the other candidate
+/// in a bi-value cell and the other cell with the candidate in a bi-location house.
+fn strong_links(board: &Board) -> Vec<Vec<(Cell, Known)>> {
+    let mut links = vec![Vec::new(); 81 * 9];
+
+    for (cell, candidates) in board.cell_candidates_with_n_candidates(2) {
+        let (first, second) = candidates.as_pair().unwrap();
+        links[node(cell, first)].push((cell, second));
+        links[node(cell, second)].push((cell, first));
+    }
+    for known in Known::iter() {
+        for house in House::iter() {
+            if let Some((first, second)) = board.house_candidate_cells(house, known).as_pair() {
+                if !links[node(first, known)].contains(&(second, known)) {
+                    links[node(first, known)].push((second, known));
+                    links[node(second, known)].push((first, known));
+                }
+            }
+        }
+    }
+
+    links
+}
+
+fn node(cell: Cell, known: Known) -> usize {
+    9 * cell.usize() + known.usize()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::io::{Parse, Parser};
+    use crate::layout::cells::cell_set::cells;
+    use crate::layout::values::known::known;
+
+    use super::*;
+
+    #[test]
+    fn both_colors_elsewhere() {
+        let parser = Parse::wiki().stop_on_error();
+        let (board, ..) = parser.parse(
+            "ik2g031141og09ikg4815olk2103golghkl4ns7olkgsg4go81jml65kg15k03086g5081545ododgsgo005l2ha2103c821s0o011k0gc0hngfi09s0p0s205jip2j005p0o80hoaj241palgditgsc21sahihqpa",
+        );
+
+        let found = find_medusas(&board, true).unwrap_or_default();
+        assert_eq!(cells!("C1 C3 C8 C9"), found.erases_from_cells(known!("2")));
+    }
+
+    #[test]
+    fn erases_uncolored() {
+        let parser = Parse::wiki().stop_on_error();
+        let (board, ..) = parser.parse(
+            "n456n2ve0hu8n8f0u0ng5g09v0u0u0ng0503nk81n2nemcm8no7gmg03g1040he8e860e0116840e0u8u8116ieiek70500he0030560g109kg4ik2u811uo056imglk09l2m4m4mg817img8121l0k4k403lg09kg",
+        );
+
+        let found = find_medusas(&board, true).unwrap_or_default();
+        assert_eq!(cells!("A2"), found.erases_from_cells(known!("1")));
+        assert_eq!(cells!("G2"), found.erases_from_cells(known!("4")));
+        assert_eq!(cells!("B2"), found.erases_from_cells(known!("5")));
+    }
+}
@@ -0,0 +1,151 @@
+use super::*;
+
+/// Colors each cluster of conjugate pairs for a digit separately
+/// and compares every two clusters that see each other.
+pub fn find_multi_colorings(board: &Board, single: bool) -> Option<Effects> {
+    let mut effects = Effects::new();
+
+    for known in Known::iter() {
+        let candidates = board.candidate_cells(known);
+        let clusters = find_clusters(board, known);
+
+        for (i, j) in (0..clusters.len()).cartesian_product(0..clusters.len()) {
+            if i == j {
+                continue;
+            }
+            let (first, second) = (clusters[i], clusters[j]);
+
+            for (a, b) in [first, (first.1, first.0)] {
+                if a.iter()
+                    .any(|cell| cell.peers().has_any(second.0) && cell.peers().has_any(second.1))
+                {
+                    // one color of the other cluster is true, so a cannot be
+                    let mut action = Action::new(Strategy::MultiColoring);
+                    action.erase_cells(a, known);
+                    clue_clusters(&mut action, known, (a, b), second);
+
+                    if effects.add_action(action) && single {
+                        return Some(effects);
+                    }
+                }
+                if i > j {
+                    continue;
+                }
+
+                for (x, y) in [second, (second.1, second.0)] {
+                    if !a.union_peers().has_any(x) {
+                        continue;
+                    }
+
+                    // a and x cannot both be true, so one of b and y must be
+                    let mut action = Action::new(Strategy::MultiColoring);
+                    action.erase_cells(
+                        (candidates - a - b - x - y) & b.union_peers() & y.union_peers(),
+                        known,
+                    );
+                    clue_clusters(&mut action, known, (a, b), (x, y));
+
+                    if effects.add_action(action) && single {
+                        return Some(effects);
+                    }
+                }
+            }
+        }
+    }
+
+    if effects.has_actions() {
+        Some(effects)
+    } else {
+        None
+    }
+}
+
+fn clue_clusters(
+    action: &mut Action,
+    known: Known,
+    (a, b): (CellSet, CellSet),
+    (x, y): (CellSet, CellSet),
+) {
+    action.clue_cells_for_known(Verdict::Secondary, a, known);
+    action.clue_cells_for_known(Verdict::Tertiary, b, known);
+    action.clue_cells_for_known(Verdict::Primary, x, known);
+    action.clue_cells_for_known(Verdict::Related, y, known);
+}
+
+/// Returns the two colors of each group of cells joined by conjugate pairs.
+fn find_clusters(board: &Board, known: Known) -> Vec<(CellSet, CellSet)> {
+    let pairs = House::iter()
+        .filter_map(|house| board.house_candidate_cells(house, known).as_pair())
+        .collect_vec();
+
+    let mut clusters = Vec::new();
+    let mut clustered = CellSet::empty();
+    for (start, _) in &pairs {
+        if clustered.has(*start) {
+            continue;
+        }
+
+        let mut colors = (CellSet::empty() + *start, CellSet::empty());
+        let mut stack = vec![(*start, true)];
+        while let Some((cell, first)) = stack.pop() {
+            for (one, other) in &pairs {
+                let next = if *one == cell {
+                    *other
+                } else if *other == cell {
+                    *one
+                } else {
+                    continue;
+                };
+                let (same, opposite) = if first {
+                    (&mut colors.0, &mut colors.1)
+                } else {
+                    (&mut colors.1, &mut colors.0)
+                };
+                if same.has(next) {
+                    // an odd loop means the puzzle is already broken
+                    return Vec::new();
+                }
+                if !opposite.has(next) {
+                    *opposite += next;
+                    stack.push((next, !first));
+                }
+            }
+        }
+
+        clustered |= colors.0 | colors.1;
+        clusters.push(colors);
+    }
+
+    clusters
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::io::{Parse, Parser};
+    use crate::layout::cells::cell_set::cells;
+    use crate::layout::values::known::known;
+
+    use super::*;
+
+    #[test]
+    fn color_wrap() {
+        let parser = Parse::wiki().stop_on_error();
+        let (board, ..) = parser.parse(
+            "n456n6ve0hu8n8f0u0ng5g09v0u0u0ng0503nk81n6nemcm8no7gmg03g1e40he8e860e0116c44e4u8u8116ieiek70500he0030560g109kg4ik2u811uo056imglk09l6m4m4mg817img8121l4k4k403lg09kg",
+        );
+
+        let found = find_multi_colorings(&board, true).unwrap_or_default();
+        assert_eq!(cells!("E8"), found.erases_from_cells(known!("1")));
+    }
+
+    #[test]
+    fn color_trap() {
+        let parser = Parse::wiki().stop_on_error();
+        let (board, ..) = parser.parse(
+            "ik2g031141og09ikg4815olk2103golghkl4ns7olkgsgcgo81jml65sg15k03086o50815c5ododgsoo805l2ha2103c821s8o811k0gc0hngfi09s0p0s205jip2j005p0o80hoaj241palgditgsc21sahihqpa",
+        );
+
+        let found = find_multi_colorings(&board, true).unwrap_or_default();
+        assert_eq!(cells!("D9"), found.erases_from_cells(known!("2")));
+    }
+}
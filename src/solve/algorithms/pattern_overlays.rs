@@ -0,0 +1,182 @@
+use std::sync::OnceLock;
+
+use super::*;
+
+/// The most template pairings to check when combining two digits.
+const MAX_PAIRINGS: usize = 250_000;
+
+static TEMPLATES: OnceLock<Vec<CellSet>> = OnceLock::new();
+
+/// Returns every placement of a digit with one cell in each row, column and block.
+pub fn templates() -> &'static [CellSet] {
+    TEMPLATES.get_or_init(|| {
+        let mut templates = Vec::with_capacity(46_656);
+        add_templates(0, CellSet::empty(), &mut templates);
+        templates
+    })
+}
+
+fn add_templates(row: u8, template: CellSet, templates: &mut Vec<CellSet>) {
+    if row == 9 {
+        templates.push(template);
+        return;
+    }
+
+    for column in 0..9 {
+        let cell = Cell::from_coords(Coord::new(row), Coord::new(column));
+        if (template & cell.peers()).is_empty() {
+            add_templates(row + 1, template + cell, templates);
+        }
+    }
+}
+
+/// Returns the templates that hold every solved cell of the digit
+/// and otherwise use only its candidates.
+fn surviving_templates(board: &Board, known: Known) -> Vec<CellSet> {
+    let fits = fits(board, known);
+    templates()
+        .iter()
+        .filter(|template| fits(template))
+        .copied()
+        .collect()
+}
+
+/// Returns a digit that cannot be placed in any template,
+/// proving the board has no solution.
+pub fn find_template_contradiction(board: &Board) -> Option<Known> {
+    Known::iter().find(|known| {
+        let fits = fits(board, *known);
+        !templates().iter().any(fits)
+    })
+}
+
+fn fits(board: &Board, known: Known) -> impl Fn(&CellSet) -> bool {
+    let solved = board.known_cells(known);
+    let allowed = solved | board.candidate_cells(known);
+
+    move |template| solved.is_subset_of(*template) && template.is_subset_of(allowed)
+}
+
+/// Removes each candidate that no valid placement of its digit covers.
+///
+/// When that finds nothing, the templates of two digits are paired,
+/// and a template survives only if the other digit has one that shares no cell with it.
+pub fn find_pattern_overlays(board: &Board, single: bool) -> Option<Effects> {
+    let mut effects = Effects::new();
+
+    let surviving = Known::iter()
+        .map(|known| surviving_templates(board, known))
+        .collect_vec();
+    if surviving.iter().any(|templates| templates.is_empty()) {
+        return None;
+    }
+
+    for known in Known::iter() {
+        let covered = surviving[known.usize()].iter().copied().union_cells();
+        let erase = board.candidate_cells(known) - covered;
+        if erase.is_empty() {
+            continue;
+        }
+
+        let mut action = Action::new_erase_cells(Strategy::PatternOverlay, erase, known);
+        action.clue_cells_for_known(
+            Verdict::Secondary,
+            covered & board.candidate_cells(known),
+            known,
+        );
+        if effects.add_action(action) && single {
+            return Some(effects);
+        }
+    }
+    if effects.has_actions() {
+        return Some(effects);
+    }
+
+    for (k1, k2) in Known::iter().collect_vec().into_iter().tuple_combinations() {
+        let (first, second) = (&surviving[k1.usize()], &surviving[k2.usize()]);
+        if first.len() * second.len() > MAX_PAIRINGS {
+            continue;
+        }
+
+        let mut action = Action::new(Strategy::PatternOverlay);
+        for (known, templates, others) in [(k1, first, second), (k2, second, first)] {
+            let covered = templates
+                .iter()
+                .filter(|template| others.iter().any(|other| (**template & *other).is_empty()))
+                .copied()
+                .union_cells();
+            action.erase_cells(board.candidate_cells(known) - covered, known);
+            action.clue_cells_for_known(
+                Verdict::Secondary,
+                covered & board.candidate_cells(known),
+                known,
+            );
+        }
+        if effects.add_action(action) && single {
+            return Some(effects);
+        }
+    }
+
+    if effects.has_actions() {
+        Some(effects)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::io::{Parse, Parser};
+    use crate::layout::cells::cell_set::cells;
+    use crate::layout::values::known::known;
+
+    use super::*;
+
+    #[test]
+    fn enumerates_every_template() {
+        assert_eq!(46_656, templates().len());
+        assert!(templates().iter().all(|template| template.len() == 9));
+    }
+
+    #[test]
+    fn single_digit() {
+        let parser = Parse::wiki().stop_on_error();
+        let (board, ..) = parser.parse(
+            "9otcksdmdk1621gupubo032sg1bk349c0s41bgv4mk09fk3694gmpmg1606266098144110k05d84a52500hg12108380h687474j403810c2i2411akg109c446a641ic813k03341cgcjc2aicieb4b4410hgere",
+        );
+
+        let found = find_pattern_overlays(&board, true).unwrap_or_default();
+        assert_eq!(cells!("A4 J3"), found.erases_from_cells(known!("1")));
+    }
+
+    #[test]
+    fn paired_digits() {
+        let parser = Parse::wiki().stop_on_error();
+        let (board, ..) = parser.parse(
+            "8850gk90ck0621gup0a8030kg18k341c0s41a050gk09ck2604gmp0g1200204098140110g05804002100hg12108100h084020g00381040g041120g10980400241g8810g03141cgc2002g8209084410hgch0",
+        );
+
+        let found = find_pattern_overlays(&board, true).unwrap_or_default();
+        assert_eq!(cells!("A2 C9"), found.erases_from_cells(known!("5")));
+    }
+
+    #[test]
+    fn finds_contradictions() {
+        let mut board = Board::new();
+        let mut effects = Effects::new();
+        let top = [0, 1, 2]
+            .map(|row| House::row(Coord::new(row)).cells())
+            .into_iter()
+            .union_cells();
+        let left = [0, 1]
+            .map(|column| House::column(Coord::new(column)).cells())
+            .into_iter()
+            .union_cells();
+
+        assert_eq!(None, find_template_contradiction(&board));
+
+        // three rows may only hold 1 in two columns
+        board.remove_candidate_from_cells(top - left, known!("1"), &mut effects);
+        assert_eq!(Some(known!("1")), find_template_contradiction(&board));
+    }
+}
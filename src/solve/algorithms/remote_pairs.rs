@@ -0,0 +1,107 @@
+use super::*;
+
+/// A chain of at least four bi-value cells with the same two candidates
+/// alternates between them, so a cell that sees cells of opposite colors
+/// may have neither candidate.
+pub fn find_remote_pairs(board: &Board, single: bool) -> Option<Effects> {
+    let mut effects = Effects::new();
+
+    let bi_values = board.cells_with_n_candidates(2);
+    let mut chained = CellSet::empty();
+
+    for start in bi_values {
+        if chained.has(start) {
+            continue;
+        }
+
+        let pair = board.candidates(start);
+        let cells = bi_values
+            .iter()
+            .filter(|cell| board.candidates(*cell) == pair)
+            .union_cells();
+        let Some(colors) = color_chain(cells, start) else {
+            // an odd loop means the puzzle is already broken
+            chained |= cells;
+            continue;
+        };
+
+        let chain = colors[0] | colors[1];
+        chained |= chain;
+        if chain.len() < 4 {
+            continue;
+        }
+
+        let erase = ((colors[0].union_peers() & colors[1].union_peers()) - chain)
+            .iter()
+            .filter(|cell| sees_remote_pair(*cell, colors))
+            .union_cells();
+        let mut action = Action::new(Strategy::RemotePairs);
+        for known in pair {
+            action.erase_cells(erase & board.candidate_cells(known), known);
+        }
+        action.clue_cells_for_knowns(Verdict::Secondary, colors[0], pair);
+        action.clue_cells_for_knowns(Verdict::Tertiary, colors[1], pair);
+
+        if effects.add_action(action) && single {
+            return Some(effects);
+        }
+    }
+
+    if effects.has_actions() {
+        Some(effects)
+    } else {
+        None
+    }
+}
+
+/// Returns true if the cell sees cells of opposite colors that do not see each other,
+/// since two that do form a naked pair instead.
+fn sees_remote_pair(cell: Cell, colors: [CellSet; 2]) -> bool {
+    let seen = cell.peers() & colors[1];
+    (cell.peers() & colors[0])
+        .iter()
+        .any(|first| !(seen - first.peers()).is_empty())
+}
+
+/// Colors the cells reachable from the start through peers with alternating colors,
+/// returning `None` if any cell is reached with both colors.
+fn color_chain(cells: CellSet, start: Cell) -> Option<[CellSet; 2]> {
+    let mut colors = [CellSet::empty() + start, CellSet::empty()];
+    let mut stack = vec![(start, 0)];
+
+    while let Some((cell, color)) = stack.pop() {
+        let other = 1 - color;
+        for next in cell.peers() & cells {
+            if colors[color].has(next) {
+                return None;
+            }
+            if !colors[other].has(next) {
+                colors[other] += next;
+                stack.push((next, other));
+            }
+        }
+    }
+
+    Some(colors)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::io::{Parse, Parser};
+    use crate::layout::cells::cell_set::cells;
+    use crate::layout::values::known::known;
+
+    use super::*;
+
+    #[test]
+    fn remote_pairs() {
+        let parser = Parse::wiki().stop_on_error();
+        let (board, ..) = parser.parse(
+            "04g10208dgdg9gbg7g0920118kckck8momkm81410gg12103140814200h80410308g11414100804218gg1410i0ik003401k1k1k093k814i11098mskek8momgm0i81201mhk3k1m41hu4i04g19i09dg219i1i",
+        );
+
+        let found = find_remote_pairs(&board, true).unwrap_or_default();
+        assert_eq!(cells!("B8"), found.erases_from_cells(known!("2")));
+        assert_eq!(cells!("A8"), found.erases_from_cells(known!("5")));
+    }
+}
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 use super::*;
 
@@ -11,7 +11,7 @@ pub fn find_singles_chains(board: &Board, single: bool) -> Option<Effects> {
         .filter(|(_, candidates)| !candidates.is_empty())
     {
         let mut nodes = CellSet::empty();
-        let mut edges: HashMap<Cell, CellSet> = HashMap::new();
+        let mut edges: BTreeMap<Cell, CellSet> = BTreeMap::new();
 
         for cells in House::iter()
             .map(|house| board.house_candidate_cells(house, known))
@@ -35,7 +35,7 @@ pub fn find_singles_chains(board: &Board, single: bool) -> Option<Effects> {
                 });
 
         let mut chains: Vec<Chain> = Vec::new();
-        let mut cell_chains: HashMap<Cell, (usize, usize)> = HashMap::new();
+        let mut cell_chains: BTreeMap<Cell, (usize, usize)> = BTreeMap::new();
 
         for candidate in candidates {
             let sees = nodes & candidate.peers();
@@ -88,14 +88,17 @@ pub fn find_singles_chains(board: &Board, single: bool) -> Option<Effects> {
             }
         }
 
-        let mut grouped: HashMap<usize, CellSet> = HashMap::new();
+        let mut grouped: BTreeMap<usize, CellSet> = BTreeMap::new();
         cell_chains.iter().for_each(|(cell, (index, _))| {
             *grouped.entry(*index).or_default() += *cell;
         });
 
-        for (_, cells) in grouped {
+        for (index, cells) in grouped {
+            let Colors((red, green)) = chains[index].colors;
             let mut action = Action::new(Strategy::SinglesChain);
             action.erase_cells(cells, known);
+            action.clue_cells_for_known(Verdict::Secondary, red, known);
+            action.clue_cells_for_known(Verdict::Tertiary, green, known);
 
             if effects.add_action(action) && single {
                 return Some(effects);
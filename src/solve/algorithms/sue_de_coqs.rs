@@ -0,0 +1,163 @@
+use super::*;
+
+/// The most cells taken from the rest of the line or block.
+const MAX_EXTRA_CELLS: usize = 3;
+
+/// Two or three cells where a block and line intersect with at least two more
+/// candidates than cells may lock those candidates together with cells
+/// from the rest of the line and the rest of the block.
+///
+/// When the line cells and block cells share no candidates and all the cells
+/// together hold exactly as many candidates as there are cells, every candidate
+/// is used once: the line cells' candidates are removed from the rest of the line,
+/// the block cells' candidates from the rest of the block, and the intersection's
+/// other candidates from both.
+pub fn find_sue_de_coqs(board: &Board, single: bool) -> Option<Effects> {
+    let mut effects = Effects::new();
+
+    for block in House::blocks_iter() {
+        for line in block.rows().iter().chain(block.columns().iter()) {
+            let intersection = block.intersect(line) & board.unknowns();
+            if intersection.len() < 2 {
+                continue;
+            }
+
+            let line_rest = (line.cells() - block.cells()) & board.unknowns();
+            let block_rest = (block.cells() - line.cells()) & board.unknowns();
+
+            for size in 2..=intersection.len() {
+                for core in intersection
+                    .iter()
+                    .collect_vec()
+                    .into_iter()
+                    .combinations(size)
+                {
+                    let core = core.into_iter().union_cells();
+                    let knowns = board.all_candidates(core);
+                    if knowns.len() < size + 2 {
+                        continue;
+                    }
+
+                    if check_extra_cells(
+                        board,
+                        single,
+                        (intersection, core, knowns),
+                        line_rest,
+                        block_rest,
+                        &mut effects,
+                    ) {
+                        return Some(effects);
+                    }
+                }
+            }
+        }
+    }
+
+    if effects.has_actions() {
+        Some(effects)
+    } else {
+        None
+    }
+}
+
+fn check_extra_cells(
+    board: &Board,
+    single: bool,
+    (intersection, core, knowns): (CellSet, CellSet, KnownSet),
+    line_rest: CellSet,
+    block_rest: CellSet,
+    effects: &mut Effects,
+) -> bool {
+    let block_groups = subsets(board, block_rest, knowns)
+        .into_iter()
+        .map(|cells| (cells, board.all_candidates(cells)))
+        .collect_vec();
+
+    for line_cells in subsets(board, line_rest, knowns) {
+        let line_knowns = board.all_candidates(line_cells);
+        if (knowns | line_knowns).len() > core.len() + line_cells.len() + MAX_EXTRA_CELLS {
+            continue;
+        }
+
+        for (block_cells, block_knowns) in &block_groups {
+            let (block_cells, block_knowns) = (*block_cells, *block_knowns);
+            if !(line_knowns & block_knowns).is_empty() {
+                continue;
+            }
+
+            let all = knowns | line_knowns | block_knowns;
+            if all.len() != core.len() + line_cells.len() + block_cells.len() {
+                continue;
+            }
+
+            let line_erase = line_knowns | (knowns - block_knowns);
+            let block_erase = block_knowns | (knowns - line_knowns);
+
+            let mut action = Action::new(Strategy::SueDeCoq);
+            for known in all {
+                let candidates = board.candidate_cells(known);
+                action.erase_cells((intersection & candidates) - core, known);
+                if line_erase.has(known) {
+                    action.erase_cells((line_rest & candidates) - line_cells, known);
+                }
+                if block_erase.has(known) {
+                    action.erase_cells((block_rest & candidates) - block_cells, known);
+                }
+            }
+            for (cells, verdict) in [
+                (core, Verdict::Primary),
+                (line_cells, Verdict::Secondary),
+                (block_cells, Verdict::Tertiary),
+            ] {
+                for cell in cells {
+                    action.clue_cell_for_knowns(verdict, cell, board.candidates(cell));
+                }
+            }
+
+            if effects.add_action(action) && single {
+                return true;
+            }
+        }
+    }
+
+    false
+}
+
+/// Returns every group of up to [`MAX_EXTRA_CELLS`] cells
+/// where each cell shares a candidate with the intersection.
+fn subsets(board: &Board, cells: CellSet, knowns: KnownSet) -> Vec<CellSet> {
+    let cells = cells
+        .iter()
+        .filter(|cell| !(board.candidates(*cell) & knowns).is_empty())
+        .collect_vec();
+
+    (1..=MAX_EXTRA_CELLS.min(cells.len()))
+        .flat_map(|size| {
+            cells
+                .iter()
+                .copied()
+                .combinations(size)
+                .map(|group| group.into_iter().union_cells())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::io::{Parse, Parser};
+    use crate::layout::cells::cell_set::cells;
+    use crate::layout::values::known::known;
+
+    use super::*;
+
+    #[test]
+    fn sue_de_coq() {
+        let parser = Parse::wiki().stop_on_error();
+        let (board, ..) = parser.parse(
+            "040g22320930g14181481148810hg0200403o022o02624411o1o1ok0a2k008303i8k9i1k3209320k812m410ig10h8205g040129821183205bi3gg0099g9g4038g13o413481031o1s98419o1k031k9sg021",
+        );
+
+        let found = find_sue_de_coqs(&board, true).unwrap_or_default();
+        assert_eq!(cells!("J6"), found.erases_from_cells(known!("5")));
+    }
+}
@@ -1,4 +1,4 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashSet};
 
 use super::naked_tuples;
 use super::*;
@@ -15,8 +15,8 @@ pub fn find(board: &Board, single: bool, full: bool) -> Option<Effects> {
     let mut effects = Effects::new();
 
     let bi_values_by_candidates = board.cell_candidates_with_n_candidates(2).fold(
-        HashMap::new(),
-        |mut map: HashMap<KnownSet, CellSet>, (cell, candidates)| {
+        BTreeMap::new(),
+        |mut map: BTreeMap<KnownSet, CellSet>, (cell, candidates)| {
             *map.entry(candidates).or_default() += cell;
             map
         },
@@ -0,0 +1,107 @@
+use super::*;
+
+/// Two bi-value cells with the same candidates that do not see each other,
+/// joined by a strong link on one candidate whose ends see one cell each,
+/// may remove the other candidate from every cell that sees both.
+///
+/// # Example
+///
+/// ```text
+///   123456789
+/// A x·····222  ←-- A1 and C7 (x) have only 1 and 2
+/// B ·········
+/// C 222···x··  ←-- 2 is removed from cells that see both
+/// D ·········
+/// E ·········
+/// F ·········
+/// G ·········
+/// H ·········
+/// J 1·····1··  ←-- 1 appears only in J1 and J7 in row J
+/// ```
+///
+/// One of J1 and J7 is 1, so either A1 or C7 must be 2.
+pub fn find_w_wings(board: &Board, single: bool) -> Option<Effects> {
+    let mut effects = Effects::new();
+
+    let bi_values = board.cells_with_n_candidates(2).iter().collect_vec();
+
+    for (c1, c2) in bi_values.into_iter().tuple_combinations() {
+        let candidates = board.candidates(c1);
+        if c1.sees(c2) || candidates != board.candidates(c2) {
+            continue;
+        }
+
+        let (k1, k2) = candidates.as_pair().unwrap();
+        for (link, erase) in [(k1, k2), (k2, k1)] {
+            let erase_cells = c1.peers() & c2.peers() & board.candidate_cells(erase);
+            if erase_cells.is_empty() {
+                continue;
+            }
+
+            for house in House::iter() {
+                let Some((a, b)) = board.house_candidate_cells(house, link).as_pair() else {
+                    continue;
+                };
+                if [a, b].iter().any(|cell| *cell == c1 || *cell == c2) {
+                    continue;
+                }
+                let (a, b) = if a.sees(c1) && b.sees(c2) {
+                    (a, b)
+                } else if b.sees(c1) && a.sees(c2) {
+                    (b, a)
+                } else {
+                    continue;
+                };
+
+                let mut action = Action::new(Strategy::WWing);
+                action.erase_cells(erase_cells, erase);
+                action.clue_cell_for_known(Verdict::Secondary, c1, erase);
+                action.clue_cell_for_known(Verdict::Secondary, c2, erase);
+                action.clue_cell_for_known(Verdict::Tertiary, c1, link);
+                action.clue_cell_for_known(Verdict::Tertiary, c2, link);
+                action.clue_cell_for_known(Verdict::Primary, a, link);
+                action.clue_cell_for_known(Verdict::Primary, b, link);
+
+                if effects.add_action(action) && single {
+                    return Some(effects);
+                }
+            }
+        }
+    }
+
+    if effects.has_actions() {
+        Some(effects)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::io::{Parse, Parser};
+    use crate::layout::cells::cell::cell;
+    use crate::layout::cells::cell_set::cells;
+    use crate::layout::values::known::known;
+
+    use super::*;
+
+    #[test]
+    fn w_wing() {
+        let parser = Parse::wiki().stop_on_error();
+        let (board, ..) = parser.parse(
+            "g40g031141800920g48150g42103080gh0k4082050g0040g8112420kg10k03082040811050d0d00gg005020821030821408011g0040h2042098010k0050gg2h005h0080h022041804gc2cg0421k010g208",
+        );
+
+        if let Some(got) = find_w_wings(&board, true) {
+            let mut action = Action::new(Strategy::WWing);
+            action.erase(cell!("B3"), known!("9"));
+            action.clue_cells_for_known(Verdict::Secondary, cells!("B8 H3"), known!("9"));
+            action.clue_cells_for_known(Verdict::Tertiary, cells!("B8 H3"), known!("5"));
+            action.clue_cells_for_known(Verdict::Primary, cells!("C3 C8"), known!("5"));
+
+            assert_eq!(format!("{:?}", action), format!("{:?}", got.actions()[0]));
+        } else {
+            panic!("not found");
+        }
+    }
+}
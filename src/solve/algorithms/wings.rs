@@ -0,0 +1,425 @@
+use std::collections::HashSet;
+
+use super::*;
+
+/// Three bi-value cells holding three candidates, a pivot seeing two wings
+/// that each share one of its candidates and have the third in common.
+pub fn find_y_wings(board: &Board, single: bool) -> Option<Effects> {
+    find_three_cell_wings(board, Strategy::YWing, single)
+}
+
+/// Three cells holding three candidates, typically a pivot with all three
+/// and two bi-value wings with one candidate in common.
+pub fn find_xyz_wings(board: &Board, single: bool) -> Option<Effects> {
+    find_three_cell_wings(board, Strategy::XYZWing, single)
+}
+
+/// Four cells holding four candidates.
+pub fn find_wxyz_wings(board: &Board, single: bool) -> Option<Effects> {
+    find_wings(board, 4, Strategy::WXYZWing, single)
+}
+
+/// Five cells holding five candidates.
+pub fn find_vwxyz_wings(board: &Board, single: bool) -> Option<Effects> {
+    find_wings(board, 5, Strategy::VWXYZWing, single)
+}
+
+/// Six cells holding six candidates.
+pub fn find_uvwxyz_wings(board: &Board, single: bool) -> Option<Effects> {
+    find_wings(board, 6, Strategy::UVWXYZWing, single)
+}
+
+/// Finds bent sets of `size` cells that hold exactly `size` candidates
+/// without all sharing a house.
+///
+/// A candidate is restricted when every cell holding it in the set sees the others,
+/// so it can appear in the set at most once.
+///
+/// - When every candidate but one is restricted, that one must appear in the set,
+///   and it may be removed from every cell that sees all of its cells in the set.
+/// - When every candidate is restricted, each one must appear in the set,
+///   and each may be removed from every cell that sees all of its cells in the set.
+///
+/// Sets of only bi-value cells are left to XY-Chain.
+fn find_wings(board: &Board, size: usize, strategy: Strategy, single: bool) -> Option<Effects> {
+    let mut effects = Effects::new();
+
+    let bi_values = board.cells_with_n_candidates(2);
+    let pool = board
+        .unknown_iter()
+        .filter(|(_, candidates)| (2..=size).contains(&candidates.len()))
+        .map(|(cell, _)| cell)
+        .union_cells();
+
+    let mut links = [CellSet::empty(); 81];
+    for cell in pool {
+        links[cell.usize()] = (cell.peers() & pool)
+            .iter()
+            .filter(|peer| !(board.candidates(*peer) & board.candidates(cell)).is_empty())
+            .union_cells();
+    }
+
+    let mut seen = HashSet::new();
+    let mut wings = Vec::new();
+    for cell in pool {
+        collect_wings(
+            board,
+            &links,
+            CellSet::empty() + cell,
+            size,
+            &mut seen,
+            &mut wings,
+        );
+    }
+
+    for wing in wings {
+        let knowns = board.all_candidates(wing);
+        if knowns.len() != size || wing.share_any_house() {
+            continue;
+        }
+        if (wing & bi_values) == wing || wing_strategy(size) != strategy {
+            continue;
+        }
+        if knowns
+            .iter()
+            .any(|known| (wing & board.candidate_cells(known)).len() < 2)
+        {
+            continue;
+        }
+
+        if let Some(action) = resolve_wing(board, strategy, wing, knowns) {
+            if effects.add_action(action) && single {
+                return Some(effects);
+            }
+        }
+    }
+
+    if effects.has_actions() {
+        Some(effects)
+    } else {
+        None
+    }
+}
+
+/// Finds the three-cell wings with a pivot search instead of [`find_wings`].
+///
+/// The two wings may not see each other, so both must be bi-value cells
+/// sharing one candidate, leaving the pivot to hold the other two for a Y-Wing
+/// or all three for an XYZ-Wing.
+fn find_three_cell_wings(board: &Board, strategy: Strategy, single: bool) -> Option<Effects> {
+    let mut effects = Effects::new();
+
+    let bi_values = board.cells_with_n_candidates(2);
+    let pivots = if strategy == Strategy::YWing {
+        bi_values
+    } else {
+        board.cells_with_n_candidates(3)
+    };
+
+    for pivot in pivots {
+        let knowns = board.candidates(pivot);
+        let peers = (pivot.peers() & bi_values)
+            .iter()
+            .filter(|peer| {
+                let candidates = board.candidates(*peer);
+                candidates != knowns && (candidates | knowns).len() == 3
+            })
+            .collect_vec();
+
+        for (c1, c2) in peers.into_iter().tuple_combinations() {
+            let (ks1, ks2) = (board.candidates(c1), board.candidates(c2));
+            if c1.sees(c2) || ks1 == ks2 || (ks1 | ks2 | knowns).len() != 3 {
+                continue;
+            }
+
+            let wing = CellSet::empty() + pivot + c1 + c2;
+            if let Some(action) = resolve_wing(board, strategy, wing, ks1 | ks2 | knowns) {
+                if effects.add_action(action) && single {
+                    return Some(effects);
+                }
+            }
+        }
+    }
+
+    if effects.has_actions() {
+        Some(effects)
+    } else {
+        None
+    }
+}
+
+/// Returns the strategy for a wing of the given size with at least one cell
+/// holding more than two candidates.
+fn wing_strategy(size: usize) -> Strategy {
+    match size {
+        3 => Strategy::XYZWing,
+        4 => Strategy::WXYZWing,
+        5 => Strategy::VWXYZWing,
+        _ => Strategy::UVWXYZWing,
+    }
+}
+
+/// Adds every set of linked cells holding at most `size` candidates
+/// with at most one unrestricted candidate and no naked subset.
+///
+/// A wing whose cells split into groups that share no candidate through a peer
+/// holds a smaller wing making the same erasures, so each wing grows only
+/// through peers sharing a candidate with one of its cells.
+fn collect_wings(
+    board: &Board,
+    links: &[CellSet; 81],
+    wing: CellSet,
+    size: usize,
+    seen: &mut HashSet<CellSet>,
+    wings: &mut Vec<CellSet>,
+) {
+    if wing.len() == size {
+        wings.push(wing);
+        return;
+    }
+
+    let linked = wing.iter().map(|cell| links[cell.usize()]).union_cells() - wing;
+    for cell in linked {
+        let next = wing + cell;
+        if !seen.insert(next)
+            || board.all_candidates(next).len() > size
+            || unrestricted(board, next).len() > 1
+            || forms_naked_subset(board, wing, cell)
+        {
+            continue;
+        }
+        collect_wings(board, links, next, size, seen, wings);
+    }
+}
+
+/// Returns the candidates whose cells in the set do not all see each other.
+fn unrestricted(board: &Board, cells: CellSet) -> KnownSet {
+    board
+        .all_candidates(cells)
+        .iter()
+        .filter(|known| {
+            let candidates = cells & board.candidate_cells(*known);
+            candidates
+                .iter()
+                .any(|cell| !(candidates - cell).is_subset_of(cell.peers()))
+        })
+        .union()
+}
+
+/// Returns true if the cell and some of the others sharing one of its houses
+/// hold as many candidates as cells, forming a naked subset within the wing.
+fn forms_naked_subset(board: &Board, wing: CellSet, cell: Cell) -> bool {
+    cell.houses().iter().any(|house| {
+        let others = ((wing & house.cells()) - cell).iter().collect_vec();
+        (1..=others.len()).any(|size| {
+            others.iter().copied().combinations(size).any(|group| {
+                board
+                    .all_candidates(group.into_iter().union_cells() + cell)
+                    .len()
+                    == size + 1
+            })
+        })
+    })
+}
+
+fn resolve_wing(
+    board: &Board,
+    strategy: Strategy,
+    wing: CellSet,
+    knowns: KnownSet,
+) -> Option<Action> {
+    let unrestricted = unrestricted(board, wing);
+    let erasing = if unrestricted.is_empty() {
+        knowns
+    } else {
+        unrestricted
+    };
+
+    let mut action = Action::new(strategy);
+    for known in knowns {
+        let cells = wing & board.candidate_cells(known);
+        if erasing.has(known) {
+            action.erase_cells(cells.peers() & board.candidate_cells(known), known);
+        }
+        let verdict = if unrestricted.has(known) {
+            Verdict::Secondary
+        } else {
+            Verdict::Primary
+        };
+        action.clue_cells_for_known(verdict, cells, known);
+    }
+
+    if action.is_empty() {
+        None
+    } else {
+        Some(action)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::io::{Parse, Parser};
+    use crate::layout::cells::cell::cell;
+    use crate::layout::cells::cell_set::cells;
+    use crate::layout::values::known::known;
+
+    use super::*;
+
+    #[test]
+    fn y_wing() {
+        let parser = Parse::wiki().stop_on_error();
+        let (board, effects, failed) = parser.parse(
+            "kq215o56hklko0k4h0kg815g5409lk03m4j0k2055052218009k00h0o0o2s81021ki0i041co4oesg10k0k11032810g003094121040h80co03co64g4kci011i005484870h0l80h81022111g10h8003410805",
+        );
+        assert_eq!(None, failed);
+        assert!(!effects.has_errors());
+
+        let found = find_y_wings(&board, false).unwrap_or_default();
+        assert_eq!(1, found.actions().len());
+        assert_eq!(Strategy::YWing, found.actions()[0].strategy());
+        assert_eq!(cells!("A3"), found.erases_from_cells(known!("5")));
+    }
+
+    #[test]
+    fn xyz_all_in_pivot() {
+        let parser = Parse::wiki().stop_on_error();
+        let (board, effects, failed) = parser.parse(
+            "88g1052g8g0341112o110q6i054gm02og2812i8i6ijg09v005g22g8841110a860hg1210605880iga21o08i41110i21g14116948i090m2i1i813ig1703a056a411m2i3i1m093281g1g11609815674320h62",
+        );
+        assert_eq!(None, failed);
+        assert!(!effects.has_errors());
+
+        if let Some(got) = find_xyz_wings(&board, true) {
+            assert_eq!(1, got.actions().len());
+
+            let mut action = Action::new(Strategy::XYZWing);
+            action.erase(cell!("F7"), known!("1"));
+            action.clue_cells_for_known(Verdict::Secondary, cells!("D9 F1 F9"), known!("1"));
+            action.clue_cells_for_known(Verdict::Primary, cells!("D9 F9"), known!("2"));
+            action.clue_cells_for_known(Verdict::Primary, cells!("F1 F9"), known!("4"));
+
+            assert_eq!(format!("{:?}", action), format!("{:?}", got.actions()[0]));
+        } else {
+            panic!("not found");
+        }
+    }
+
+    #[test]
+    fn wxyz_all_in_pivot() {
+        let parser = Parse::wiki().stop_on_error();
+        let (board, effects, failed) = parser.parse(
+            "q2i2o2p2050h410992110ho20941o0052182050941b212b0h2o20h41j6h6h009h4810h32i0j4090h8103h0k470g2810hl021l4h20609090686e20ie02g11g18i4190b2g1092g8205oih221051i9009c2c2",
+        );
+        assert_eq!(None, failed);
+        assert!(!effects.has_errors());
+
+        if let Some(got) = find_wxyz_wings(&board, true) {
+            assert_eq!(1, got.actions().len());
+
+            let mut action = Action::new(Strategy::WXYZWing);
+            action.erase(cell!("D2"), known!("9"));
+            action.clue_cells_for_known(Verdict::Secondary, cells!("D3 D4 D6 F1"), known!("9"));
+            action.clue_cells_for_known(Verdict::Primary, cells!("D3 F1"), known!("1"));
+            action.clue_cells_for_known(Verdict::Primary, cells!("D3 D6"), known!("2"));
+            action.clue_cells_for_known(Verdict::Primary, cells!("D3 D4 D6"), known!("5"));
+
+            assert_eq!(format!("{:?}", action), format!("{:?}", got.actions()[0]));
+        } else {
+            panic!("not found");
+        }
+    }
+
+    #[test]
+    fn wxyz_not_all_in_pivot() {
+        let parser = Parse::wiki().stop_on_error();
+        let (board, effects, failed) = parser.parse(
+            "810h053030094103g160m00903s00hb0942411m00344s4o4a0090hi009812403i40h4111gg051g09h0410321810330413g9gb005g1090990215k5k14g19g034gd01gg10903b094240503g1813g30091g41",
+        );
+        assert_eq!(None, failed);
+        assert!(!effects.has_errors());
+
+        if let Some(got) = find_wxyz_wings(&board, true) {
+            assert_eq!(1, got.actions().len());
+
+            let mut action = Action::new(Strategy::WXYZWing);
+            action.erase_cells(cells!("F6 G5 J5"), known!("5"));
+            action.clue_cells_for_known(Verdict::Secondary, cells!("E5 G6 J6"), known!("5"));
+            action.clue_cells_for_known(Verdict::Primary, cells!("D6 J6"), known!("6"));
+            action.clue_cells_for_known(Verdict::Primary, cells!("D6 G6"), known!("2"));
+            action.clue_cells_for_known(Verdict::Primary, cells!("D6 E5"), known!("9"));
+
+            assert_eq!(format!("{:?}", action), format!("{:?}", got.actions()[0]));
+        } else {
+            panic!("not found");
+        }
+    }
+
+    #[test]
+    fn wxyz_ignores_naked_pairs() {
+        let parser = Parse::wiki().stop_on_error();
+        let (board, effects, failed) = parser.parse(
+            "4m8111kcka06gk21gk06i6i6j4o20h4108p44k09m4n4s0b403okpk8e0goem0k222o411u621h6o6l00h09o4o6s61241g281053209giii8e068e0h11g12141065232620c8884hggigig1140h032140948409",
+        );
+        assert_eq!(None, failed);
+        assert!(!effects.has_errors());
+
+        assert_eq!(None, find_wxyz_wings(&board, true));
+    }
+
+    #[test]
+    fn wxyz_each_primary_must_appear_at_least_twice() {
+        let parser = Parse::wiki().stop_on_error();
+        let (board, effects, failed) = parser.parse(
+            "k020081102k0800h0503s0d4g4210gl008h00gk014084481h02002210gk0g4441003810804s2s22009k20g10k01008k20h80k204k02008050h41h020h0028080112002gg0409k0kgk0k2k2801g0821041g"
+        );
+        assert_eq!(None, failed);
+        assert!(!effects.has_errors());
+
+        assert_eq!(None, find_wxyz_wings(&board, true));
+    }
+
+    #[test]
+    fn wxyz_ignores_xy_chains() {
+        let parser = Parse::wiki().stop_on_error();
+        let (board, effects, failed) = parser.parse(
+            "0305090h5050a0a0g10h11210381g10541094181g10921050h1103054i4i50522109g181094281g10h4211242421g11105098141030h114a42e0k64aq20h24814q0560k24qi22811g1210i90161q828c41"
+        );
+        assert_eq!(None, failed);
+        assert!(!effects.has_errors());
+
+        assert_eq!(None, find_wxyz_wings(&board, true));
+    }
+
+    #[test]
+    fn vwxyz_wing() {
+        let parser = Parse::wiki().stop_on_error();
+        let (board, ..) = parser.parse(
+            "g1a42432bm8m18419a11e00962e2g10g05820g034409d4c4h0219042g172057q4q811878c6ec76n2na4anch80h440h7481n84803h87c4644k60hse116c8aee2144kmk2sece5c9qde091181424621440ig1",
+        );
+
+        if let Some(got) = find_vwxyz_wings(&board, true) {
+            let mut action = Action::new(Strategy::VWXYZWing);
+            action.erase(cell!("F3"), known!("7"));
+            action.clue_cells_for_known(Verdict::Secondary, cells!("C3 D1 D3 E3"), known!("7"));
+            action.clue_cells_for_known(Verdict::Primary, cells!("D1 D3 E3"), known!("1"));
+            action.clue_cells_for_known(Verdict::Primary, cells!("A3 C3 E3"), known!("2"));
+            action.clue_cells_for_known(Verdict::Primary, cells!("D3 E3"), known!("5"));
+            action.clue_cells_for_known(Verdict::Primary, cells!("A3 D3 E3"), known!("6"));
+
+            assert_eq!(format!("{:?}", action), format!("{:?}", got.actions()[0]));
+        } else {
+            panic!("not found");
+        }
+    }
+
+    #[test]
+    fn uvwxyz_wing() {
+        let parser = Parse::wiki().stop_on_error();
+        let (board, ..) = parser.parse(
+            "9otcksdmdk1621gupubo032sg1bk349c0s41bgv4mk09fk3694gmpmg1606266098144110k05d84a52500hg12108380h687474j403810c2i2411akg109c446a641ic813k03341cgcjc2aicieb4b4410hgere",
+        );
+
+        let found = find_uvwxyz_wings(&board, true).unwrap_or_default();
+        assert_eq!(cells!("G7"), found.erases_from_cells(known!("2")));
+    }
+}
@@ -1,4 +1,4 @@
-use std::collections::{HashMap, VecDeque};
+use std::collections::{BTreeMap, VecDeque};
 use std::fmt;
 use std::rc::Rc;
 
@@ -66,13 +66,13 @@ pub fn find_xy_chains(board: &Board, single: bool) -> Option<Effects> {
 
 /// Builds graphs from cells with two candidates and merges them when they connect.
 struct Forest {
-    graphs: HashMap<Cell, Graph>,
+    graphs: BTreeMap<Cell, Graph>,
 }
 
 impl Forest {
     fn new() -> Self {
         Forest {
-            graphs: HashMap::new(),
+            graphs: BTreeMap::new(),
         }
     }
 
@@ -110,7 +110,7 @@ struct Graph {
     root: Cell,
     cells: [CellSet; 9],
     peers: [CellSet; 9],
-    nodes: HashMap<Cell, Rc<Node>>,
+    nodes: BTreeMap<Cell, Rc<Node>>,
 }
 
 impl Graph {
@@ -123,7 +123,7 @@ impl Graph {
         peers[node.min_known.usize()] = root.peers();
         peers[node.max_known.usize()] = root.peers();
 
-        let mut nodes = HashMap::new();
+        let mut nodes = BTreeMap::new();
         nodes.insert(root, Rc::clone(node));
 
         Graph {
@@ -0,0 +1,235 @@
+use crate::puzzle::{Difficulty, Strategy};
+
+use super::{find_technique, Technique, NON_PEER_TECHNIQUES};
+
+/// Named lists of techniques for the [`Solver`][`super::Solver`] to use in order.
+///
+/// Profiles are read one per line in the form `name = list`, where the list
+/// is parsed by [`select_techniques`]. Blank lines and those starting with `#`
+/// are ignored, and later profiles replace earlier ones with the same name.
+///
+/// ```text
+/// # puzzles from this source may have more than one solution
+/// no-uniqueness = all, -uniqueness
+/// beginner = naked single, hidden single, intersection removal, basic
+/// ```
+#[derive(Clone, Debug)]
+pub struct Profiles {
+    profiles: Vec<(String, Vec<Technique>)>,
+}
+
+impl Profiles {
+    /// Returns the built-in `default` and `no-uniqueness` profiles.
+    pub fn new() -> Self {
+        let all = NON_PEER_TECHNIQUES.to_vec();
+        let unique = all
+            .iter()
+            .filter(|technique| !technique.strategy().assumes_unique_solution())
+            .copied()
+            .collect();
+
+        Self {
+            profiles: vec![
+                ("default".to_string(), all),
+                ("no-uniqueness".to_string(), unique),
+            ],
+        }
+    }
+
+    /// Adds the profiles defined in the text to the built-in profiles.
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut profiles = Self::new();
+
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let Some((name, list)) = line.split_once('=') else {
+                return Err(format!(
+                    "Line {}: expected \"name = techniques\"",
+                    index + 1
+                ));
+            };
+            let name = name.trim();
+            if name.is_empty() {
+                return Err(format!("Line {}: missing profile name", index + 1));
+            }
+            let techniques = select_techniques(list)
+                .map_err(|error| format!("Line {}: {}", index + 1, error))?;
+
+            profiles.add(name, techniques);
+        }
+
+        Ok(profiles)
+    }
+
+    pub fn add(&mut self, name: &str, techniques: Vec<Technique>) {
+        self.profiles.retain(|(existing, _)| existing != name);
+        self.profiles.push((name.to_string(), techniques));
+    }
+
+    pub fn get(&self, name: &str) -> Option<&[Technique]> {
+        self.profiles
+            .iter()
+            .find(|(existing, _)| existing == name)
+            .map(|(_, techniques)| techniques.as_slice())
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.profiles.iter().map(|(name, _)| name.as_str())
+    }
+}
+
+impl Default for Profiles {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Builds an ordered list of techniques from a comma-separated list.
+///
+/// Each entry names a strategy by its label or name, a [`Difficulty`] to include
+/// every technique of that difficulty, `uniqueness` for those that assume
+/// the puzzle has a single solution, or `all`. Entries starting with `-`
+/// remove techniques instead, and a list that starts with a removal
+/// begins with every technique. Duplicates keep their first position.
+pub fn select_techniques(list: &str) -> Result<Vec<Technique>, String> {
+    let mut selected = Vec::new();
+
+    for (index, entry) in list
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+        .enumerate()
+    {
+        if let Some(removed) = entry.strip_prefix('-') {
+            if index == 0 {
+                selected = NON_PEER_TECHNIQUES.to_vec();
+            }
+            let removed = expand(removed.trim())?;
+            selected.retain(|technique| !removed.contains(technique));
+        } else {
+            for technique in expand(entry)? {
+                if !selected.contains(&technique) {
+                    selected.push(technique);
+                }
+            }
+        }
+    }
+
+    Ok(selected)
+}
+
+/// Removes the techniques in the comma-separated list parsed like [`select_techniques`].
+pub fn exclude_techniques(techniques: &mut Vec<Technique>, list: &str) -> Result<(), String> {
+    for entry in list
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+    {
+        let removed = expand(entry.strip_prefix('-').unwrap_or(entry).trim())?;
+        techniques.retain(|technique| !removed.contains(technique));
+    }
+
+    Ok(())
+}
+
+fn expand(entry: &str) -> Result<Vec<Technique>, String> {
+    let filtered = |keep: &dyn Fn(&Technique) -> bool| {
+        NON_PEER_TECHNIQUES
+            .iter()
+            .filter(|technique| keep(technique))
+            .copied()
+            .collect()
+    };
+
+    if entry.eq_ignore_ascii_case("all") {
+        return Ok(NON_PEER_TECHNIQUES.to_vec());
+    }
+    if entry.eq_ignore_ascii_case("uniqueness") {
+        return Ok(filtered(&|technique| {
+            technique.strategy().assumes_unique_solution()
+        }));
+    }
+    if let Ok(difficulty) = entry.parse::<Difficulty>() {
+        return Ok(filtered(&|technique| technique.difficulty() == difficulty));
+    }
+
+    let strategy = entry.parse::<Strategy>()?;
+    find_technique(strategy)
+        .map(|technique| vec![technique])
+        .ok_or_else(|| format!("No technique implements {}", strategy))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strategies(techniques: &[Technique]) -> Vec<Strategy> {
+        techniques
+            .iter()
+            .map(|technique| technique.strategy())
+            .collect()
+    }
+
+    #[test]
+    fn selects_techniques_in_the_given_order() {
+        let techniques = select_techniques("hidden single, naked single, x-wing").unwrap();
+
+        assert_eq!(
+            vec![
+                Strategy::HiddenSingle,
+                Strategy::NakedSingle,
+                Strategy::XWing
+            ],
+            strategies(&techniques)
+        );
+    }
+
+    #[test]
+    fn removes_excluded_techniques() {
+        let techniques = select_techniques("-uniqueness, -y-wing").unwrap();
+
+        assert_eq!(NON_PEER_TECHNIQUES.len() - 7, techniques.len());
+        assert!(techniques
+            .iter()
+            .all(|technique| !technique.strategy().assumes_unique_solution()
+                && technique.strategy() != Strategy::YWing));
+
+        let mut basic = select_techniques("basic, naked single").unwrap();
+        exclude_techniques(&mut basic, "naked pair").unwrap();
+        assert_eq!(Strategy::NakedTriple, basic[0].strategy());
+        assert_eq!(Strategy::NakedSingle, basic.last().unwrap().strategy());
+    }
+
+    #[test]
+    fn rejects_unknown_techniques() {
+        assert!(select_techniques("naked single, nothing").is_err());
+        assert!(select_techniques("given").is_err());
+    }
+
+    #[test]
+    fn parses_profiles() {
+        let profiles = Profiles::parse(
+            "# comment\n\nsingles = naked single, hidden single\nno-uniqueness = all\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            vec![Strategy::NakedSingle, Strategy::HiddenSingle],
+            strategies(profiles.get("singles").unwrap())
+        );
+        assert_eq!(
+            NON_PEER_TECHNIQUES.len(),
+            profiles.get("no-uniqueness").unwrap().len()
+        );
+        assert!(profiles.get("default").is_some());
+        assert!(profiles.get("missing").is_none());
+        assert_eq!(
+            Err("Line 1: expected \"name = techniques\"".to_string()),
+            Profiles::parse("singles").map(|_| ())
+        );
+    }
+}
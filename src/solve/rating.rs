@@ -0,0 +1,261 @@
+use std::fmt;
+
+use crate::puzzle::{Action, Effects, Strategy};
+
+/// Steps rated above this need more than singles and intersections.
+const HARD_STEP: u16 = 28;
+
+/// Rates a puzzle on a numeric scale modeled after Sudoku Explainer (SE)
+/// using the hardest step required to solve it and the number of hard steps.
+///
+/// Ratings are stored in tenths so they may be compared and sorted exactly.
+/// Puzzles are ordered first by their hardest step and then by the number
+/// of steps that required more than singles and intersections.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Rating {
+    hardest: u16,
+    hard_steps: u16,
+    hard_total: u32,
+}
+
+impl Rating {
+    /// Rates the actions applied to solve a puzzle.
+    pub fn new(actions: &Effects) -> Self {
+        actions
+            .actions()
+            .iter()
+            .fold(Rating::default(), |rating, action| {
+                rating.with_step(rate_action(action))
+            })
+    }
+
+    fn with_step(self, step: u16) -> Self {
+        let hard = step > HARD_STEP;
+        Self {
+            hardest: self.hardest.max(step),
+            hard_steps: self.hard_steps + hard as u16,
+            hard_total: self.hard_total + if hard { step as u32 } else { 0 },
+        }
+    }
+
+    /// Returns the SE rating of the hardest step.
+    pub fn hardest(&self) -> f32 {
+        self.hardest as f32 / 10.0
+    }
+
+    /// Returns the number of steps that needed more than singles and intersections.
+    pub fn hard_steps(&self) -> usize {
+        self.hard_steps as usize
+    }
+
+    /// Returns the average SE rating of the hard steps.
+    pub fn hard_average(&self) -> f32 {
+        if self.hard_steps == 0 {
+            0.0
+        } else {
+            self.hard_total as f32 / self.hard_steps as f32 / 10.0
+        }
+    }
+}
+
+impl fmt::Display for Rating {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.1}", self.hardest())
+    }
+}
+
+/// Returns the rating of a single action in tenths,
+/// adding the SE length penalty for chains.
+pub fn rate_action(action: &Action) -> u16 {
+    let base = rate_strategy(action.strategy());
+    match action.strategy() {
+        Strategy::SinglesChain
+        | Strategy::XYChain
+        | Strategy::XCycle
+        | Strategy::AlternatingInferenceChain => {
+            base + chain_length_penalty(action.collect_clues().len())
+        }
+        _ => base,
+    }
+}
+
+/// Returns the rating for a strategy in tenths, before any chain length penalty.
+///
+/// These follow the SE scale where it has an equivalent technique
+/// and fall between neighboring techniques where it does not.
+pub const fn rate_strategy(strategy: Strategy) -> u16 {
+    match strategy {
+        Strategy::Given => 0,
+        Strategy::Solve => 0,
+        Strategy::Erase => 0,
+        Strategy::Peer => 0,
+
+        Strategy::HiddenSingle => 15,
+        Strategy::NakedSingle => 23,
+        Strategy::IntersectionRemoval => 26,
+        Strategy::PointingPair => 26,
+        Strategy::PointingTriple => 26,
+        Strategy::BoxLineReduction => 28,
+
+        Strategy::NakedPair => 30,
+        Strategy::XWing => 32,
+        Strategy::HiddenPair => 34,
+        Strategy::NakedTriple => 36,
+        Strategy::Swordfish => 38,
+        Strategy::HiddenTriple => 40,
+        Strategy::Skyscraper => 40,
+        Strategy::TwoStringKite => 41,
+        Strategy::YWing => 42,
+        Strategy::EmptyRectangle => 43,
+        Strategy::XYZWing => 44,
+        Strategy::WWing => 44,
+        Strategy::RemotePairs => 45,
+        Strategy::UniqueRectangle => 45,
+        Strategy::AvoidableRectangle => 45,
+        Strategy::AlmostUniqueRectangle => 46,
+        Strategy::ExtendedUniqueRectangle => 47,
+        Strategy::HiddenUniqueRectangle => 48,
+
+        Strategy::NakedQuad => 50,
+        Strategy::SueDeCoq => 50,
+        Strategy::Jellyfish => 52,
+        Strategy::FinnedXWing => 34,
+        Strategy::SashimiXWing => 35,
+        Strategy::FinnedSwordfish => 40,
+        Strategy::SashimiSwordfish => 41,
+        Strategy::FinnedJellyfish => 54,
+        Strategy::SashimiJellyfish => 55,
+        Strategy::FrankenFish => 60,
+        Strategy::MutantFish => 64,
+        Strategy::HiddenQuad => 54,
+        Strategy::WXYZWing => 55,
+        Strategy::VWXYZWing => 57,
+        Strategy::UVWXYZWing => 59,
+        Strategy::Bug => 56,
+        Strategy::Fireworks => 58,
+
+        Strategy::SinglesChain => 65,
+        Strategy::XYChain => 66,
+        Strategy::MultiColoring => 66,
+        Strategy::Medusa3D => 68,
+        Strategy::XCycle => 66,
+        Strategy::AlternatingInferenceChain => 70,
+        Strategy::AlsXZ => 75,
+        Strategy::AlsXYWing => 78,
+        Strategy::DeathBlossom => 82,
+        Strategy::PatternOverlay => 80,
+        Strategy::Nishio => 76,
+        Strategy::CellForcingChain => 83,
+        Strategy::UnitForcingChain => 84,
+        Strategy::DigitForcingChain => 86,
+        Strategy::JuniorExocet => 92,
+
+        Strategy::BruteForce => 110,
+    }
+}
+
+/// Returns the penalty in tenths that SE adds to a chain with the given number of nodes.
+///
+/// Chains up to four nodes have no penalty. Beyond that the ceiling grows
+/// alternately by a half and a third, adding a tenth each time it is passed.
+pub const fn chain_length_penalty(length: usize) -> u16 {
+    let mut ceiling = 4;
+    let mut penalty = 0;
+    let mut odd = false;
+
+    while length > ceiling {
+        penalty += 1;
+        ceiling = if odd {
+            ceiling * 4 / 3
+        } else {
+            ceiling * 3 / 2
+        };
+        odd = !odd;
+    }
+
+    penalty
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::io::{Parse, Parser};
+    use crate::layout::cells::cell::cell;
+    use crate::layout::values::known::known;
+    use crate::layout::{Cell, Known};
+    use crate::puzzle::Verdict;
+    use crate::solve::{Resolution, Solver, Timings};
+
+    use super::*;
+
+    #[test]
+    fn chain_length_penalties_match_se() {
+        let penalties = (0..=20).map(chain_length_penalty).collect::<Vec<u16>>();
+
+        assert_eq!(
+            vec![0, 0, 0, 0, 0, 1, 1, 2, 2, 3, 3, 3, 3, 4, 4, 4, 4, 5, 5, 5, 5],
+            penalties
+        );
+    }
+
+    #[test]
+    fn chains_are_penalized_by_length() {
+        let mut short = Action::new(Strategy::XYChain);
+        short.erase(cell!("C4"), known!("9"));
+        short.clue_cell_for_known(Verdict::Secondary, cell!("B7"), known!("2"));
+        short.clue_cell_for_known(Verdict::Tertiary, cell!("B7"), known!("8"));
+        short.clue_cell_for_known(Verdict::Secondary, cell!("C9"), known!("9"));
+        short.clue_cell_for_known(Verdict::Tertiary, cell!("C9"), known!("2"));
+
+        let mut long = short.clone();
+        long.clue_cell_for_known(Verdict::Secondary, cell!("F4"), known!("8"));
+        long.clue_cell_for_known(Verdict::Tertiary, cell!("F4"), known!("9"));
+
+        assert_eq!(66, rate_action(&short));
+        assert_eq!(67, rate_action(&long));
+    }
+
+    #[test]
+    fn rates_hardest_step_then_hard_steps() {
+        let mut effects = Effects::new();
+        effects.add_action(Action::new_set(
+            Strategy::HiddenSingle,
+            cell!("A1"),
+            known!("1"),
+        ));
+        effects.add_action(Action::new_erase(Strategy::XWing, cell!("A2"), known!("2")));
+        effects.add_action(Action::new_erase(
+            Strategy::NakedPair,
+            cell!("A3"),
+            known!("3"),
+        ));
+
+        let rating = Rating::new(&effects);
+
+        assert_eq!(3.2, rating.hardest());
+        assert_eq!(2, rating.hard_steps());
+        assert_eq!(3.1, rating.hard_average());
+
+        let mut harder = effects.clone();
+        harder.add_action(Action::new_erase(
+            Strategy::NakedPair,
+            cell!("A4"),
+            known!("4"),
+        ));
+        assert!(Rating::new(&harder) > rating);
+    }
+
+    #[test]
+    fn rates_solved_puzzles() {
+        let board = Parse::packed().parse_simple(
+            "..2...376 .1..3.5.. .......9. 9..85...1 ...3.4... 2...97..3 .8....... ..3.4..6. 147...2..",
+        );
+
+        let resolution = Solver::new(false).solve(&board, &Effects::new(), &mut Timings::new());
+        let Resolution::Solved(_, actions, _) = &resolution else {
+            panic!("puzzle not solved");
+        };
+
+        assert_eq!(Some(Rating::new(actions)), resolution.rating());
+        assert!(resolution.rating().unwrap().hardest() >= 4.4);
+    }
+}
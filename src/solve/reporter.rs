@@ -3,6 +3,7 @@ use std::time::Duration;
 
 use crate::layout::{Cell, Known};
 use crate::puzzle::{Action, Board, Difficulty, Effects, Strategy};
+use crate::solve::Rating;
 
 /// One of these methods is called for each puzzle run through the solver.
 pub trait Reporter {
@@ -41,12 +42,14 @@ pub trait Reporter {
     );
 
     /// The puzzle was fully solved.
+    #[allow(clippy::too_many_arguments)]
     fn solved(
         &self,
         givens: &str,
         start: &Board,
         solution: &Board,
         difficulty: Difficulty,
+        rating: Rating,
         runtime: Duration,
         counts: &HashMap<Strategy, i32>,
     );
@@ -2,7 +2,7 @@ use std::time::Instant;
 
 use crate::io::Cancelable;
 use crate::puzzle::{Action, Board, ChangeResult, Changer, Difficulty, Effects, Options};
-use crate::solve::{find_brute_force, Timings, NON_PEER_TECHNIQUES};
+use crate::solve::{find_brute_force, Rating, Technique, Timings, NON_PEER_TECHNIQUES};
 
 pub enum Resolution {
     /// Returned when the user interrupts the solver
@@ -31,6 +31,14 @@ impl Resolution {
     pub fn is_solved(&self) -> bool {
         matches!(self, Resolution::Solved(..))
     }
+
+    /// Returns the rating of the actions taken to solve the puzzle, if it was solved.
+    pub fn rating(&self) -> Option<Rating> {
+        match self {
+            Resolution::Solved(_, actions, _) => Some(Rating::new(actions)),
+            _ => None,
+        }
+    }
 }
 
 /// Attempts to solve puzzles using the available strategy algorithms.
@@ -44,6 +52,9 @@ pub struct Solver {
     /// The check option for the solve command verifies that the puzzle is solvable
     /// after each action to detect when an algorithm gives faulty deductions.
     check: bool,
+
+    /// The techniques to try in order, starting over after each one succeeds.
+    techniques: Vec<Technique>,
 }
 
 impl Solver {
@@ -52,9 +63,16 @@ impl Solver {
             changer: Changer::new(Options::errors()),
             cancelable: Cancelable::new(),
             check,
+            techniques: NON_PEER_TECHNIQUES.to_vec(),
         }
     }
 
+    /// Replaces the default techniques with these, tried in the given order.
+    pub fn with_techniques(mut self, techniques: Vec<Technique>) -> Self {
+        self.techniques = techniques;
+        self
+    }
+
     pub fn solve(&self, start: &Board, _: &Effects, timings: &mut Timings) -> Resolution {
         let mut board = *start;
         let mut applied = Effects::new();
@@ -66,7 +84,7 @@ impl Solver {
             }
 
             let mut action = None;
-            for solver in NON_PEER_TECHNIQUES {
+            for solver in &self.techniques {
                 if self.cancelable.is_canceled() {
                     return Resolution::Canceled(board, applied, difficulty);
                 }
@@ -3,7 +3,7 @@ use crate::puzzle::{Board, Difficulty, Effects, Strategy};
 use super::algorithms;
 
 /// Names and categorizes a solver technique.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug)]
 pub struct Technique {
     strategy: Strategy,
     difficulty: Difficulty,
@@ -38,11 +38,27 @@ impl Technique {
     }
 }
 
+impl PartialEq for Technique {
+    fn eq(&self, other: &Self) -> bool {
+        self.strategy == other.strategy
+    }
+}
+
+impl Eq for Technique {}
+
+/// Returns the technique that implements the strategy, if any.
+pub fn find_technique(strategy: Strategy) -> Option<Technique> {
+    TECHNIQUES
+        .iter()
+        .find(|technique| technique.strategy == strategy)
+        .copied()
+}
+
 type TechniqueFunc = fn(board: &Board, single: bool) -> Option<Effects>;
 
 /// All techniques implemented by this solver.
 #[rustfmt::skip]
-pub const TECHNIQUES: [Technique; 28] = [
+pub const TECHNIQUES: [Technique; 54] = [
     Technique::new(Strategy::Peer, algorithms::find_peers),
     Technique::new(Strategy::NakedSingle, algorithms::find_naked_singles),
     Technique::new(Strategy::HiddenSingle, algorithms::find_hidden_singles),
@@ -63,6 +79,8 @@ pub const TECHNIQUES: [Technique; 28] = [
     Technique::new(Strategy::Swordfish, algorithms::find_swordfish),
     Technique::new(Strategy::XYZWing, algorithms::find_xyz_wings),
     Technique::new(Strategy::AvoidableRectangle, algorithms::find_avoidable_rectangles),
+    Technique::new(Strategy::WWing, algorithms::find_w_wings),
+    Technique::new(Strategy::RemotePairs, algorithms::find_remote_pairs),
 
     Technique::new(Strategy::Jellyfish,algorithms::find_jellyfish),
     Technique::new(Strategy::Skyscraper,algorithms::find_skyscrapers),
@@ -73,28 +91,70 @@ pub const TECHNIQUES: [Technique; 28] = [
     Technique::new(Strategy::ExtendedUniqueRectangle, algorithms::find_extended_unique_rectangles),
     Technique::new(Strategy::HiddenUniqueRectangle, algorithms::find_hidden_unique_rectangles),
     Technique::new(Strategy::WXYZWing, algorithms::find_wxyz_wings),
+    Technique::new(Strategy::SueDeCoq, algorithms::find_sue_de_coqs),
+    Technique::new(Strategy::VWXYZWing, algorithms::find_vwxyz_wings),
 
     // BUG causes unavoidable rectangles in several puzzles which UR fixes
     Technique::new(Strategy::Bug,algorithms::find_bugs),
+
+    Technique::new(Strategy::FinnedXWing, algorithms::find_finned_x_wings),
+    Technique::new(Strategy::SashimiXWing, algorithms::find_sashimi_x_wings),
+    Technique::new(Strategy::FinnedSwordfish, algorithms::find_finned_swordfish),
+    Technique::new(Strategy::SashimiSwordfish, algorithms::find_sashimi_swordfish),
+    Technique::new(Strategy::FinnedJellyfish, algorithms::find_finned_jellyfish),
+    Technique::new(Strategy::SashimiJellyfish, algorithms::find_sashimi_jellyfish),
+
+    Technique::new(Strategy::MultiColoring, algorithms::find_multi_colorings),
+    Technique::new(Strategy::Medusa3D, algorithms::find_medusas),
+
+    Technique::new(Strategy::XCycle, algorithms::find_x_cycles),
+    Technique::new(Strategy::AlternatingInferenceChain, algorithms::find_alternating_inference_chains),
+    Technique::new(Strategy::AlsXZ, algorithms::find_als_xz),
+    Technique::new(Strategy::AlsXYWing, algorithms::find_als_xy_wings),
+    Technique::new(Strategy::DeathBlossom, algorithms::find_death_blossoms),
+    Technique::new(Strategy::UVWXYZWing, algorithms::find_uvwxyz_wings),
+
+    Technique::new(Strategy::PatternOverlay, algorithms::find_pattern_overlays),
+    // complex fish are slow and rarely find anything the techniques above miss
+    Technique::new(Strategy::FrankenFish, algorithms::find_franken_fish),
+    Technique::new(Strategy::MutantFish, algorithms::find_mutant_fish),
+
+    Technique::new(Strategy::Nishio, algorithms::find_nishios),
+    Technique::new(Strategy::CellForcingChain, algorithms::find_cell_forcing_chains),
+    Technique::new(Strategy::UnitForcingChain, algorithms::find_unit_forcing_chains),
+    Technique::new(Strategy::DigitForcingChain, algorithms::find_digit_forcing_chains),
+
+    Technique::new(Strategy::JuniorExocet, algorithms::find_junior_exocets),
 ];
 
 /// All techniques except finding peers.
 #[rustfmt::skip]
-pub const NON_PEER_TECHNIQUES: [Technique; 27] = [
+pub const NON_PEER_TECHNIQUES: [Technique; 53] = [
     TECHNIQUES[1],  TECHNIQUES[2],  TECHNIQUES[3],  TECHNIQUES[4],  TECHNIQUES[5],
     TECHNIQUES[6],  TECHNIQUES[7],  TECHNIQUES[8],  TECHNIQUES[9],  TECHNIQUES[10],
     TECHNIQUES[11], TECHNIQUES[12], TECHNIQUES[13], TECHNIQUES[14], TECHNIQUES[15],
     TECHNIQUES[16], TECHNIQUES[17], TECHNIQUES[18], TECHNIQUES[19], TECHNIQUES[20],
     TECHNIQUES[21], TECHNIQUES[22], TECHNIQUES[23], TECHNIQUES[24], TECHNIQUES[25],
-    TECHNIQUES[26], TECHNIQUES[27],
+    TECHNIQUES[26], TECHNIQUES[27], TECHNIQUES[28], TECHNIQUES[29], TECHNIQUES[30],
+    TECHNIQUES[31], TECHNIQUES[32], TECHNIQUES[33], TECHNIQUES[34], TECHNIQUES[35],
+    TECHNIQUES[36], TECHNIQUES[37], TECHNIQUES[38], TECHNIQUES[39], TECHNIQUES[40],
+    TECHNIQUES[41], TECHNIQUES[42], TECHNIQUES[43], TECHNIQUES[44], TECHNIQUES[45],
+    TECHNIQUES[46], TECHNIQUES[47], TECHNIQUES[48], TECHNIQUES[49], TECHNIQUES[50],
+    TECHNIQUES[51], TECHNIQUES[52], TECHNIQUES[53],
 ];
 
 /// All techniques that cannot be handled automatically by the [`Board`].
 #[rustfmt::skip]
-pub const MANUAL_TECHNIQUES: [Technique; 25] = [
+pub const MANUAL_TECHNIQUES: [Technique; 51] = [
     TECHNIQUES[3],  TECHNIQUES[4],  TECHNIQUES[5],  TECHNIQUES[6],  TECHNIQUES[7],
     TECHNIQUES[8],  TECHNIQUES[9],  TECHNIQUES[10], TECHNIQUES[11], TECHNIQUES[12],
     TECHNIQUES[13], TECHNIQUES[14], TECHNIQUES[15], TECHNIQUES[16], TECHNIQUES[17],
     TECHNIQUES[18], TECHNIQUES[19], TECHNIQUES[20], TECHNIQUES[21], TECHNIQUES[22],
     TECHNIQUES[23], TECHNIQUES[24], TECHNIQUES[25], TECHNIQUES[26], TECHNIQUES[27],
+    TECHNIQUES[28], TECHNIQUES[29], TECHNIQUES[30], TECHNIQUES[31], TECHNIQUES[32],
+    TECHNIQUES[33], TECHNIQUES[34], TECHNIQUES[35], TECHNIQUES[36], TECHNIQUES[37],
+    TECHNIQUES[38], TECHNIQUES[39], TECHNIQUES[40], TECHNIQUES[41], TECHNIQUES[42],
+    TECHNIQUES[43], TECHNIQUES[44], TECHNIQUES[45], TECHNIQUES[46], TECHNIQUES[47],
+    TECHNIQUES[48], TECHNIQUES[49], TECHNIQUES[50], TECHNIQUES[51], TECHNIQUES[52],
+    TECHNIQUES[53],
 ];
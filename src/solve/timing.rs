@@ -8,6 +8,7 @@ use crate::puzzle::Strategy;
 
 /// Tracks the number of times a strategy was called, the number of times it found a solution,
 /// and the total time spent in the strategy.
+#[derive(Default)]
 pub struct Timings {
     timings: HashMap<Strategy, HashMap<usize, (usize, Duration)>>,
     found: usize,
@@ -33,6 +34,21 @@ impl Timings {
         *total += duration;
     }
 
+    /// Adds the calls tracked by another set of timings, such as one kept by a worker thread.
+    pub fn merge(&mut self, other: Timings) {
+        self.found += other.found;
+        self.duration += other.duration;
+
+        for (strategy, found_times) in other.timings {
+            let entry = self.timings.entry(strategy).or_default();
+            for (found, (count, duration)) in found_times {
+                let (total_count, total_duration) = entry.entry(found).or_default();
+                *total_count += count;
+                *total_duration += duration;
+            }
+        }
+    }
+
     pub fn print_details(&self) {
         println!("Strategy                            Called       Found       Total    Call Avg         Avg");
         for (strategy, found_times) in self.timings.iter().sorted_by(|(a, _), (b, _)| a.cmp(b)) {
@@ -102,3 +118,36 @@ impl Timings {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merge_adds_calls_by_strategy_and_found() {
+        let mut timings = Timings::new();
+        timings.add(Strategy::XWing, 0, Duration::from_micros(10));
+        timings.add(Strategy::XWing, 2, Duration::from_micros(20));
+
+        let mut other = Timings::new();
+        other.add(Strategy::XWing, 2, Duration::from_micros(30));
+        other.add(Strategy::YWing, 1, Duration::from_micros(40));
+
+        timings.merge(other);
+
+        assert_eq!(5, timings.found);
+        assert_eq!(Duration::from_micros(100), timings.duration);
+        assert_eq!(
+            (2, Duration::from_micros(50)),
+            timings.timings[&Strategy::XWing][&2]
+        );
+        assert_eq!(
+            (1, Duration::from_micros(10)),
+            timings.timings[&Strategy::XWing][&0]
+        );
+        assert_eq!(
+            (1, Duration::from_micros(40)),
+            timings.timings[&Strategy::YWing][&1]
+        );
+    }
+}